@@ -0,0 +1,82 @@
+//! Generates a `#[test]` per accepted answer in the answer stores, `answers.toml` and `answers/<year>.toml`, included
+//! by `tests/answers.rs`. Each test only runs when its day is compiled in by its feature.
+#![deny(clippy::all, clippy::pedantic)]
+
+// The stores are read with the crate's own reader, so that escaped strings such as art come out as `aoc` sees them.
+#[allow(dead_code)]
+#[path = "src/toml.rs"]
+mod toml;
+
+use std::{env, fmt::Write, fs, path::Path};
+use toml::{Document, Value};
+
+/// The year of `answers.toml`, as `aoc::YEAR`.
+const YEAR: u16 = 2021;
+
+fn main() {
+    let mut stores = vec![(YEAR, "answers.toml".to_string())];
+    for file in fs::read_dir("answers").into_iter().flatten().flatten() {
+        let name = file.file_name().to_string_lossy().into_owned();
+        if let Some(year) = name.strip_suffix(".toml").and_then(|y| y.parse().ok()) {
+            stores.push((year, format!("answers/{name}")));
+        }
+    }
+    stores.sort();
+
+    // Watching the directory notices new stores as well as changed ones.
+    println!("cargo:rerun-if-changed=answers");
+    let mut tests = String::new();
+    for (year, store) in stores {
+        println!("cargo:rerun-if-changed={store}");
+        let answers = fs::read_to_string(&store).unwrap_or_default();
+        let answers = Document::parse(&answers).unwrap_or_else(|e| panic!("{store}: {e}"));
+        write_tests(&mut tests, year, &answers);
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("answer_tests.rs");
+    fs::write(out, tests).unwrap();
+}
+
+/// Append a test for every answer in the store of `year`.
+fn write_tests(tests: &mut String, year: u16, answers: &Document) {
+    // The days of other years are named after their year, as by `aoc::day_dir`.
+    let prefix = if year == YEAR {
+        String::new()
+    } else {
+        format!("y{year}")
+    };
+
+    for table in &answers.tables {
+        let Some(day) = table
+            .name
+            .strip_prefix("day")
+            .and_then(|d| d.parse::<u8>().ok())
+        else {
+            continue;
+        };
+        for (key, value) in &table.entries {
+            let part = match key.as_str() {
+                "part1" => "One",
+                "part2" => "Two",
+                _ => continue,
+            };
+            let answer = match value {
+                Value::String(text) => text.clone(),
+                Value::Integer(n) => n.to_string(),
+                _ => continue,
+            };
+
+            let (feature, name) = if prefix.is_empty() {
+                (format!("day{day}"), format!("day{day}"))
+            } else {
+                (format!("{prefix}-day{day}"), format!("{prefix}_day{day}"))
+            };
+            writeln!(
+                tests,
+                "#[test]\n#[cfg(feature = \"{feature}\")]\nfn {name}_part{n}() {{\n    aoc::answers::assert_answer({year}, {day}, aoc::Part::{part}, {answer:?});\n}}\n",
+                n = if part == "One" { 1 } else { 2 },
+            )
+            .unwrap();
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Memory [Usage] of parsing and solving, counted by the opt-in [`CountingAllocator`].
+//!
+//! The `aoc` binary only installs the allocator when built with the `count-allocations` feature, since counting puts
+//! a few atomic operations on every allocation. Without it, every [Usage] is zero and the memory columns are left out
+//! of the output.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Whether the crate was built with the `count-allocations` feature, so that the binaries count allocations.
+pub const ENABLED: bool = cfg!(feature = "count-allocations");
+
+/// The bookkeeping of the [`CountingAllocator`].
+struct Counters {
+    allocations: AtomicUsize,
+    bytes: AtomicUsize,
+    live: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl Counters {
+    const fn new() -> Self {
+        Self {
+            allocations: AtomicUsize::new(0),
+            bytes: AtomicUsize::new(0),
+            live: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    fn allocated(&self, size: usize) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(size, Ordering::Relaxed);
+        let live = self.live.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn freed(&self, size: usize) {
+        self.live.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, Usage) {
+        let allocations = self.allocations.load(Ordering::Relaxed);
+        let bytes = self.bytes.load(Ordering::Relaxed);
+        let live = self.live.load(Ordering::Relaxed);
+        self.peak.store(live, Ordering::Relaxed);
+
+        let output = f();
+
+        let usage = Usage {
+            allocations: self.allocations.load(Ordering::Relaxed) - allocations,
+            bytes: self.bytes.load(Ordering::Relaxed) - bytes,
+            peak: self.peak.load(Ordering::Relaxed).saturating_sub(live),
+        };
+        (output, usage)
+    }
+}
+
+static COUNTERS: Counters = Counters::new();
+
+/// A global allocator that counts allocations and tracks the live bytes before handing them to the [System]
+/// allocator.
+///
+/// Install it in a binary to make [allocations] and [measure] meaningful:
+///
+/// ```no_run
+/// #[global_allocator]
+/// static ALLOCATOR: aoc::alloc::CountingAllocator = aoc::alloc::CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        COUNTERS.allocated(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        COUNTERS.allocated(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // A reallocation counts as a new allocation of the new size that frees the old one.
+        COUNTERS.allocated(new_size);
+        COUNTERS.freed(layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        COUNTERS.freed(layout.size());
+        System.dealloc(ptr, layout);
+    }
+}
+
+/// The number of allocations made so far through the [`CountingAllocator`], or 0 when it is not installed.
+#[must_use]
+pub fn allocations() -> usize {
+    COUNTERS.allocations.load(Ordering::Relaxed)
+}
+
+/// The memory [Usage] of a phase such as parsing or solving a part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize,
+    /// The bytes allocated in total, including memory that was freed again.
+    pub bytes: usize,
+    /// The most bytes that were live at once, on top of what was live when the phase started.
+    pub peak: usize,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!(
+            "{} / {} / {}",
+            self.allocations,
+            size(self.bytes),
+            size(self.peak)
+        ))
+    }
+}
+
+/// A number of `bytes` in B, KiB or MiB.
+fn size(bytes: usize) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let b = bytes as f64;
+    if bytes < 1 << 10 {
+        format!("{bytes}B")
+    } else if bytes < 1 << 20 {
+        format!("{:.1}KiB", b / f64::from(1 << 10))
+    } else {
+        format!("{:.1}MiB", b / f64::from(1 << 20))
+    }
+}
+
+/// Run `f` and return its result with the memory [Usage] of the call.
+///
+/// The counters are global, so allocations of other threads are counted too, and the peak of an enclosing
+/// measurement only covers the time after this one. The usage is therefore only meaningful while nothing else runs,
+/// which is why the runner refuses `--jobs` above 1 when counting.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    COUNTERS.measure(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{size, Counters, Usage};
+
+    #[test]
+    fn usage_is_counted_and_rendered() {
+        // The allocator is not installed in the tests, so drive counters of its own by hand.
+        let counters = Counters::new();
+        counters.allocated(10);
+        let ((), usage) = counters.measure(|| {
+            counters.allocated(100);
+            counters.allocated(50);
+            counters.freed(100);
+            counters.allocated(20);
+            counters.freed(70);
+        });
+        assert_eq!(
+            usage,
+            Usage {
+                allocations: 3,
+                bytes: 170,
+                peak: 150,
+            }
+        );
+
+        let usage = Usage {
+            allocations: 3,
+            bytes: 3 << 20,
+            peak: 1536,
+        };
+        assert_eq!(format!("{usage:>20}"), " 3 / 3.0MiB / 1.5KiB");
+        assert_eq!(size(1023), "1023B");
+    }
+}
@@ -0,0 +1,231 @@
+//! The [Answer] to a part of a puzzle, shared by the runner, the verifier and the answer store.
+//!
+//! Most parts answer with an integer, but a few draw capital letters as `#`/`.` art. Every answer is eventually typed
+//! into the website, so two answers are equal when they would be submitted as the same text: `Unsigned(7)` equals
+//! `Text("7")`, art that [`ocr`](crate::ocr) can read equals the letters it spells, and other art ignores trailing
+//! whitespace and blank lines around it.
+use crate::{json, ocr, toml};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+/// The [Answer] to a part of a puzzle.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    /// An integer of any size in decimal, such as a product that overflows 64 bits.
+    Big(String),
+    Text(String),
+    /// Letters drawn over several lines, such as `#`/`.` art.
+    Art(String),
+}
+
+impl Answer {
+    /// Read an [Answer] as written by a person or stored in `answers.toml`: integers become numbers, text spanning
+    /// several lines becomes art and anything else stays text.
+    #[must_use]
+    pub fn parse(text: &str) -> Self {
+        let trimmed = text.trim();
+        if trimmed.contains('\n') {
+            return Answer::Art(text.to_string());
+        }
+        if let Ok(n) = trimmed.parse() {
+            return Answer::Unsigned(n);
+        }
+        if let Ok(n) = trimmed.parse() {
+            return Answer::Signed(n);
+        }
+        let digits = trimmed.strip_prefix('-').unwrap_or(trimmed);
+        if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Answer::Big(trimmed.to_string());
+        }
+        Answer::Text(trimmed.to_string())
+    }
+
+    /// The text that would be submitted for the [Answer], which decides equality: integers without leading zeros,
+    /// trimmed text, and art read into its letters when [`ocr`] knows them.
+    #[must_use]
+    pub fn canonical(&self) -> String {
+        match self {
+            Answer::Signed(n) => n.to_string(),
+            Answer::Unsigned(n) => n.to_string(),
+            Answer::Big(n) => {
+                let (sign, digits) = n.strip_prefix('-').map_or(("", n.as_str()), |d| ("-", d));
+                match digits.trim_start_matches('0') {
+                    "" => "0".to_string(),
+                    digits => format!("{sign}{digits}"),
+                }
+            }
+            Answer::Text(text) => text.trim().to_string(),
+            Answer::Art(art) => {
+                let lines: Vec<&str> = art.lines().map(str::trim_end).collect();
+                let start = lines.iter().position(|l| !l.is_empty()).unwrap_or(0);
+                let end = lines
+                    .iter()
+                    .rposition(|l| !l.is_empty())
+                    .map_or(0, |i| i + 1);
+                let art = lines[start..end.max(start)].join("\n");
+                ocr::read_str(&art).unwrap_or(art)
+            }
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical() == other.canonical()
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state);
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => n.fmt(f),
+            Answer::Unsigned(n) => n.fmt(f),
+            Answer::Big(n) | Answer::Text(n) | Answer::Art(n) => f.pad(n),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($variant:ident: $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::$variant(n.into())
+            }
+        })*
+    };
+}
+
+from_integer!(Signed: i8, i16, i32, i64);
+from_integer!(Unsigned: u8, u16, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Unsigned(n as u64)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Answer::Signed(n as i64)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        Answer::Big(n.to_string())
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        Answer::Big(n.to_string())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// Integers are JSON numbers, as long as they fit the reader, and everything else is a string.
+impl From<&Answer> for json::Value {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Signed(n) => json::Value::from(*n),
+            Answer::Unsigned(n) => json::Value::from(*n),
+            Answer::Big(_) => match answer.canonical().parse() {
+                Ok(n) => json::Value::Integer(n),
+                Err(_) => json::Value::String(answer.canonical()),
+            },
+            Answer::Text(text) | Answer::Art(text) => json::Value::from(text.as_str()),
+        }
+    }
+}
+
+/// Integers that fit are TOML integers, and everything else is a string.
+impl From<&Answer> for toml::Value {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Signed(n) => toml::Value::Integer(*n),
+            Answer::Unsigned(n) => i64::try_from(*n)
+                .map_or_else(|_| toml::Value::String(n.to_string()), toml::Value::Integer),
+            Answer::Big(_) => toml::Value::String(answer.canonical()),
+            Answer::Text(text) | Answer::Art(text) => toml::Value::String(text.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+    use crate::{json, toml};
+
+    #[test]
+    fn parse_and_display() {
+        assert!(matches!(Answer::parse(" 42\n"), Answer::Unsigned(42)));
+        assert!(matches!(Answer::parse("-7"), Answer::Signed(-7)));
+        assert!(matches!(
+            Answer::parse("123456789012345678901234"),
+            Answer::Big(_)
+        ));
+        assert!(matches!(Answer::parse("ABCDEFGH"), Answer::Text(_)));
+        assert!(matches!(Answer::parse("#..#\n####\n"), Answer::Art(_)));
+
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(format!("{:>5}", Answer::from(12usize)), "   12");
+        assert_eq!(format!("{:<4}|", Answer::from("ab")), "ab  |");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn equality_is_by_submitted_text() {
+        assert_eq!(Answer::from(7u32), Answer::from("7"));
+        assert_eq!(Answer::from(7i64), Answer::Unsigned(7));
+        assert_eq!(Answer::Big("007".to_string()), Answer::from(7u8));
+        assert_eq!(Answer::Big("-0".to_string()), Answer::from(0usize));
+        assert_ne!(Answer::from(-7i64), Answer::from(7u64));
+        assert_eq!(
+            Answer::Art("\n#..#  \n####\n\n".to_string()),
+            Answer::parse("#..#\n####")
+        );
+        assert_ne!(Answer::from("abc"), Answer::from("ABC"));
+
+        let hi = "#..#..###\n#..#...#.\n####...#.\n#..#...#.\n#..#...#.\n#..#..###\n";
+        assert_eq!(Answer::parse(hi), Answer::from("HI"));
+        assert_eq!(Answer::parse(hi).to_string(), hi);
+    }
+
+    #[test]
+    fn serialization() {
+        let json = |answer: &Answer| json::Value::from(answer).to_string();
+        assert_eq!(json(&Answer::from(5usize)), "5");
+        assert_eq!(json(&Answer::from(-5i32)), "-5");
+        assert_eq!(json(&Answer::from(u128::MAX)), format!("\"{}\"", u128::MAX));
+        assert_eq!(json(&Answer::parse("#.\n.#")), r##""#.\n.#""##);
+
+        let toml = |answer: &Answer| toml::Value::from(answer).to_string();
+        assert_eq!(toml(&Answer::from(5usize)), "5");
+        assert_eq!(toml(&Answer::from(u64::MAX)), format!("\"{}\"", u64::MAX));
+        assert_eq!(toml(&Answer::from("7")), "\"7\"");
+        assert_eq!(toml(&Answer::parse("#.\n.#")), r##""#.\n.#""##);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::{
+    registry::Entry,
+    toml::{Document, TomlError, Value},
+    Answer, Error, Part,
+};
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AnswersError {
+    #[error("reading {} failed", .path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("writing {} failed", .path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("{} is invalid", .path.display())]
+    Toml {
+        path: PathBuf,
+        #[source]
+        source: TomlError,
+    },
+}
+
+/// The store of accepted [Answers] of a year, kept in `answers.toml` as one `[day<N>]` table with `part1` and `part2`
+/// keys. Other years than [YEAR](crate::YEAR) have a store of their own in `answers/`, such as `answers/2022.toml`.
+///
+/// Integer answers are stored as TOML integers when they fit, and every other [Answer] as a string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    document: Document,
+}
+
+impl Answers {
+    /// The default location of the store of `year`, below the crate root.
+    #[must_use]
+    pub fn default_path(year: u16) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(Self::store(year))
+    }
+
+    /// The path of the store of `year` relative to the crate root, where `build.rs` looks for it.
+    pub(crate) fn store(year: u16) -> PathBuf {
+        if year == crate::YEAR {
+            PathBuf::from("answers.toml")
+        } else {
+            Path::new("answers").join(format!("{year}.toml"))
+        }
+    }
+
+    /// Load the store from `path`, which is empty when the file does not exist.
+    ///
+    /// # Errors
+    /// Returns an [`AnswersError`] when the file cannot be read or is not valid TOML.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let toml = match fs::read_to_string(path) {
+            Ok(toml) => toml,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(AnswersError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+
+        let document = Document::parse(&toml).map_err(|source| AnswersError::Toml {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(Self { document })
+    }
+
+    /// Write the store to `path`.
+    ///
+    /// # Errors
+    /// Returns an [`AnswersError`] when the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        fs::write(path, self.document.to_string()).map_err(|source| AnswersError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// The accepted answer of `part` of `day`.
+    #[must_use]
+    pub fn get(&self, day: u8, part: Part) -> Option<Answer> {
+        let value = self.document.table(&table(day))?.get(&key(part))?;
+        Some(match value {
+            Value::String(s) => Answer::parse(s),
+            Value::Integer(n) => Answer::from(*n),
+            value => Answer::Text(value.to_string()),
+        })
+    }
+
+    /// Record `answer` as the accepted answer of `part` of `day`.
+    pub fn set(&mut self, day: u8, part: Part, answer: impl Into<Answer>) {
+        self.document
+            .table_mut(&table(day))
+            .set(&key(part), Value::from(&answer.into()));
+    }
+
+    /// Every recorded `(day, part, answer)`, in file order.
+    #[must_use]
+    pub fn all(&self) -> Vec<(u8, Part, Answer)> {
+        let mut answers = Vec::new();
+        for t in &self.document.tables {
+            let Some(day) = t.name.strip_prefix("day").and_then(|d| d.parse().ok()) else {
+                continue;
+            };
+            for part in Part::ALL {
+                if let Some(answer) = self.get(day, part) {
+                    answers.push((day, part, answer));
+                }
+            }
+        }
+        answers
+    }
+}
+
+fn table(day: u8) -> String {
+    format!("day{day}")
+}
+
+fn key(part: Part) -> String {
+    format!("part{part}")
+}
+
+/// The outcome of recomputing an accepted answer.
+#[derive(Debug)]
+pub enum Verification {
+    /// The recomputed answer matches the accepted one.
+    Match,
+    /// There is no accepted answer to compare against.
+    Unknown {
+        actual: Answer,
+    },
+    Mismatch {
+        expected: Answer,
+        actual: Answer,
+    },
+    Failed(Error),
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verification::Match => write!(f, "ok"),
+            Verification::Unknown { actual } => write!(f, "no accepted answer (got {actual})"),
+            Verification::Mismatch { expected, actual } => {
+                write!(f, "MISMATCH: expected {expected}, got {actual}")
+            }
+            Verification::Failed(e) => write!(f, "FAILED: {e}"),
+        }
+    }
+}
+
+/// Solve every implemented part of `entry` for `input` and compare the answers with the accepted [Answers].
+///
+/// # Errors
+/// Returns an error when the `input` cannot be parsed.
+pub fn verify(
+    entry: &Entry,
+    input: &str,
+    answers: &Answers,
+) -> crate::Result<Vec<(Part, Verification)>> {
+    let run = entry.solve(input, &Part::ALL)?;
+
+    Ok(run
+        .parts
+        .into_iter()
+        .filter_map(|p| {
+            let verification = match (p.answer, answers.get(entry.day, p.part)) {
+                (Err(Error::Unimplemented), None) => return None,
+                (Err(e), _) => Verification::Failed(e),
+                (Ok(actual), Some(expected)) if actual == expected => Verification::Match,
+                (Ok(actual), Some(expected)) => Verification::Mismatch { expected, actual },
+                (Ok(actual), None) => Verification::Unknown { actual },
+            };
+            Some((p.part, verification))
+        })
+        .collect())
+}
+
+/// Recompute the answer of `part` of `day` of `year` from the real input and panic unless it is `expected`.
+///
+/// This backs the tests generated from the answer stores.
+///
+/// # Panics
+/// Panics when the input cannot be read or parsed, or when the answer differs from `expected`.
+pub fn assert_answer(year: u16, day: u8, part: Part, expected: &str) {
+    let entry = crate::registry::find(year, day).expect("day is registered");
+    let input = crate::read_input(year, day, None).expect("input is readable");
+    let run = entry.solve(&input, &[part]).expect("input parses");
+    let answer = run.parts[0].answer.as_ref().expect("part is solved");
+    assert_eq!(
+        *answer,
+        Answer::parse(expected),
+        "{year} day {day} part {part}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answers;
+    use crate::{Answer, Part};
+    use std::{env, fs};
+
+    #[test]
+    fn load_set_and_save() {
+        let path = env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        fs::write(&path, "[day1]\npart1 = \"7\"\npart2 = 5\n").unwrap();
+
+        let mut answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some(Answer::from(7u8)));
+        assert_eq!(answers.get(1, Part::Two), Some(Answer::from("5")));
+        assert_eq!(answers.get(2, Part::One), None);
+
+        answers.set(2, Part::One, "150");
+        answers.set(2, Part::Two, 900usize);
+        answers.save(&path).unwrap();
+        let saved = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            saved,
+            "[day1]\npart1 = \"7\"\npart2 = 5\n\n[day2]\npart1 = \"150\"\npart2 = 900\n"
+        );
+        assert_eq!(
+            answers.all(),
+            [
+                (1, Part::One, Answer::from("7")),
+                (1, Part::Two, Answer::from("5")),
+                (2, Part::One, Answer::from("150")),
+                (2, Part::Two, Answer::from("900")),
+            ]
+        );
+    }
+
+    #[test]
+    fn stores_are_per_year() {
+        let path = Answers::default_path(crate::YEAR);
+        assert_eq!(path.file_name().unwrap(), "answers.toml");
+        assert_eq!(
+            Answers::default_path(2022),
+            path.with_file_name("answers").join("2022.toml")
+        );
+    }
+
+    #[test]
+    fn missing_store_is_empty() {
+        let answers = Answers::load(&env::temp_dir().join("aoc-no-such-answers.toml")).unwrap();
+        assert_eq!(answers, Answers::default());
+    }
+
+    #[test]
+    #[cfg(feature = "day1")]
+    fn verify_example() {
+        use super::{verify, Verification};
+        use crate::{registry::find, YEAR};
+
+        let mut answers = Answers::default();
+        answers.set(1, Part::One, "7");
+        answers.set(1, Part::Two, "6");
+
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+        let verifications = verify(find(YEAR, 1).unwrap(), input, &answers).unwrap();
+
+        assert!(matches!(verifications[0], (Part::One, Verification::Match)));
+        assert!(matches!(
+            &verifications[1],
+            (Part::Two, Verification::Mismatch { expected, actual }) if *expected == Answer::from(6u8) && *actual == Answer::from(5u8)
+        ));
+    }
+}
@@ -0,0 +1,350 @@
+use crate::{
+    alloc::{self, Usage},
+    json::Value,
+    registry::solve_part,
+    Error, Part, Result, Solution,
+};
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum BaselineError {
+    #[error(transparent)]
+    Json(#[from] crate::json::JsonError),
+    #[error("baseline is missing the `{0}` field")]
+    MissingField(&'static str),
+    #[error("unknown phase in baseline: {0}")]
+    UnknownPhase(String),
+}
+
+/// How many times each [Phase] is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Untimed runs to warm up caches and the branch predictor.
+    pub warmup: usize,
+    /// Timed runs the [Stats] are computed from.
+    pub iterations: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 10,
+            iterations: 100,
+        }
+    }
+}
+
+/// A benchmarked step of a day's [Solution].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Part(Part::One) => f.pad("part1"),
+            Phase::Part(Part::Two) => f.pad("part2"),
+        }
+    }
+}
+
+impl TryFrom<&str> for Phase {
+    type Error = BaselineError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::Part(Part::One)),
+            "part2" => Ok(Phase::Part(Part::Two)),
+            phase => Err(BaselineError::UnknownPhase(phase.to_string())),
+        }
+    }
+}
+
+/// Timing [Stats] over all timed iterations of a [Phase].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+    /// The allocations and bytes per iteration and the highest peak of any iteration, counted by the
+    /// [`CountingAllocator`](crate::alloc::CountingAllocator).
+    pub memory: Usage,
+}
+
+impl Stats {
+    /// Compute the [Stats] of non-empty `samples`.
+    fn new(samples: &mut [Duration], memory: Usage) -> Self {
+        samples.sort_unstable();
+        let n = samples.len();
+        let total: Duration = samples.iter().sum();
+
+        Self {
+            min: samples[0],
+            median: samples[n / 2],
+            // The nearest-rank percentile.
+            p95: samples[(n * 95).div_ceil(100) - 1],
+            mean: total / u32::try_from(n).unwrap_or(u32::MAX),
+            memory: Usage {
+                allocations: memory.allocations / n,
+                bytes: memory.bytes / n,
+                peak: memory.peak,
+            },
+        }
+    }
+}
+
+/// The [Stats] of one [Phase] of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// Run `f` `config.warmup` times and then time it `config.iterations` times.
+fn measure<T>(config: &Config, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..config.warmup {
+        black_box(f()?);
+    }
+
+    let iterations = config.iterations.max(1);
+    let mut samples = Vec::with_capacity(iterations);
+    // The totals over all iterations, except for the peak, which is the highest.
+    let mut memory = Usage::default();
+    for _ in 0..iterations {
+        let ((output, elapsed), usage) = alloc::measure(|| {
+            let start = Instant::now();
+            let output = f();
+            (output, start.elapsed())
+        });
+        memory.allocations += usage.allocations;
+        memory.bytes += usage.bytes;
+        memory.peak = memory.peak.max(usage.peak);
+        samples.push(elapsed);
+        drop(black_box(output?));
+    }
+
+    Ok(Stats::new(&mut samples, memory))
+}
+
+/// Benchmark parsing `input` and solving both parts of the [Solution] `S`.
+///
+/// Parts that are not implemented yet are left out.
+///
+/// # Errors
+/// Returns an error when parsing or a part fails.
+pub fn bench<S: Solution>(input: &str, config: &Config) -> Result<Vec<Measurement>> {
+    let mut measurements = vec![Measurement {
+        day: S::DAY,
+        phase: Phase::Parse,
+        stats: measure(config, || S::parse(input))?,
+    }];
+
+    let parsed = S::parse(input)?;
+    for part in Part::ALL {
+        match solve_part::<S>(&parsed, part) {
+            Err(Error::Unimplemented) => continue,
+            Err(e) => return Err(e),
+            Ok(_) => {}
+        }
+        measurements.push(Measurement {
+            day: S::DAY,
+            phase: Phase::Part(part),
+            stats: measure(config, || solve_part::<S>(&parsed, part))?,
+        });
+    }
+
+    Ok(measurements)
+}
+
+/// Serialize `measurements` as a baseline JSON document.
+#[must_use]
+pub fn to_json(measurements: &[Measurement]) -> Value {
+    let nanos = |d: Duration| Value::Integer(i128::try_from(d.as_nanos()).unwrap_or(i128::MAX));
+    let benchmarks = measurements
+        .iter()
+        .map(|m| {
+            Value::object([
+                ("day", Value::from(m.day)),
+                ("phase", Value::from(m.phase.to_string())),
+                ("min_ns", nanos(m.stats.min)),
+                ("median_ns", nanos(m.stats.median)),
+                ("p95_ns", nanos(m.stats.p95)),
+                ("mean_ns", nanos(m.stats.mean)),
+                (
+                    "allocations",
+                    Value::from(m.stats.memory.allocations as u64),
+                ),
+                ("bytes", Value::from(m.stats.memory.bytes as u64)),
+                ("peak_bytes", Value::from(m.stats.memory.peak as u64)),
+            ])
+        })
+        .collect();
+
+    Value::object([
+        ("version", Value::from(1u8)),
+        ("benchmarks", Value::Array(benchmarks)),
+    ])
+}
+
+/// Read the measurements of a baseline JSON document written by [`to_json`].
+///
+/// # Errors
+/// Returns a [`BaselineError`] when the document is not valid JSON or is missing fields.
+pub fn from_json(json: &str) -> std::result::Result<Vec<Measurement>, BaselineError> {
+    let value = Value::parse(json)?;
+    let benchmarks = value
+        .get("benchmarks")
+        .and_then(Value::as_array)
+        .ok_or(BaselineError::MissingField("benchmarks"))?;
+
+    benchmarks
+        .iter()
+        .map(|b| {
+            let field = |name: &'static str| {
+                b.get(name)
+                    .and_then(Value::as_u64)
+                    .ok_or(BaselineError::MissingField(name))
+            };
+            let duration = |name| field(name).map(Duration::from_nanos);
+            // Baselines saved before bytes were counted do not have them.
+            let size = |name| {
+                b.get(name)
+                    .and_then(Value::as_u64)
+                    .map_or(0, |n| usize::try_from(n).unwrap_or(usize::MAX))
+            };
+            let phase = b
+                .get("phase")
+                .and_then(Value::as_str)
+                .ok_or(BaselineError::MissingField("phase"))?;
+
+            Ok(Measurement {
+                day: u8::try_from(field("day")?).map_err(|_| BaselineError::MissingField("day"))?,
+                phase: Phase::try_from(phase)?,
+                stats: Stats {
+                    min: duration("min_ns")?,
+                    median: duration("median_ns")?,
+                    p95: duration("p95_ns")?,
+                    mean: duration("mean_ns")?,
+                    memory: Usage {
+                        allocations: usize::try_from(field("allocations")?).unwrap_or(usize::MAX),
+                        bytes: size("bytes"),
+                        peak: size("peak_bytes"),
+                    },
+                },
+            })
+        })
+        .collect()
+}
+
+/// The relative change of the median of `current` compared to the matching measurement in `baseline`.
+///
+/// Returns `None` when the baseline has no such measurement.
+#[must_use]
+pub fn change(current: &Measurement, baseline: &[Measurement]) -> Option<f64> {
+    let previous = baseline
+        .iter()
+        .find(|b| b.day == current.day && b.phase == current.phase)?;
+    let previous = previous.stats.median.as_secs_f64();
+    if previous == 0.0 {
+        return None;
+    }
+    Some(current.stats.median.as_secs_f64() / previous - 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{change, from_json, to_json, Measurement, Phase, Stats};
+    use crate::{alloc::Usage, Part};
+    use std::time::Duration;
+
+    #[test]
+    fn stats() {
+        let mut samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        let memory = Usage {
+            allocations: 40,
+            bytes: 400,
+            peak: 30,
+        };
+        let stats = Stats::new(&mut samples, memory);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(11));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.mean, Duration::from_micros(10_500));
+        assert_eq!(stats.memory.allocations, 2);
+        assert_eq!(stats.memory.bytes, 20);
+        assert_eq!(stats.memory.peak, 30);
+    }
+
+    #[test]
+    #[cfg(feature = "day1")]
+    fn bench_day1() {
+        use super::{bench, Config};
+        use crate::day1::Day1;
+
+        let config = Config {
+            warmup: 1,
+            iterations: 3,
+        };
+        let measurements = bench::<Day1>("1\n2\n3\n4", &config).unwrap();
+        let phases: Vec<Phase> = measurements.iter().map(|m| m.phase).collect();
+        assert_eq!(
+            phases,
+            [Phase::Parse, Phase::Part(Part::One), Phase::Part(Part::Two)]
+        );
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let stats = Stats {
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(20),
+            p95: Duration::from_nanos(30),
+            mean: Duration::from_nanos(21),
+            memory: Usage {
+                allocations: 4,
+                bytes: 128,
+                peak: 64,
+            },
+        };
+        let measurements = vec![Measurement {
+            day: 2,
+            phase: Phase::Part(Part::Two),
+            stats,
+        }];
+
+        let json = to_json(&measurements).to_string();
+        assert_eq!(from_json(&json).unwrap(), measurements);
+    }
+
+    #[test]
+    fn change_against_baseline() {
+        let measurement = |median| Measurement {
+            day: 1,
+            phase: Phase::Parse,
+            stats: Stats {
+                min: Duration::ZERO,
+                median: Duration::from_micros(median),
+                p95: Duration::ZERO,
+                mean: Duration::ZERO,
+                memory: Usage::default(),
+            },
+        };
+
+        let baseline = [measurement(100)];
+        let change = change(&measurement(150), &baseline).unwrap();
+        assert!((change - 0.5).abs() < 1e-9);
+        assert_eq!(super::change(&measurement(150), &[]), None);
+    }
+}
@@ -0,0 +1,178 @@
+use aoc::{
+    alloc,
+    bench::{self, Config, Measurement},
+    input::InputError,
+    registry, Error,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+/// The arguments of the `bench` command.
+pub struct Args {
+    days: Vec<u8>,
+    all: bool,
+    config: Config,
+    baseline: PathBuf,
+    save: bool,
+    /// The relative slowdown of the median, in percent, reported as a regression.
+    threshold: f64,
+}
+
+impl Args {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut days = Vec::new();
+        let mut all = false;
+        let mut config = aoc::config::get().bench;
+        let mut baseline = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("target")
+            .join(aoc::year_file("aoc-bench.json", crate::year()));
+        let mut save = false;
+        let mut threshold = 10.0;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--iterations" | "-n" => config.iterations = crate::value(&mut args, &arg)?,
+                "--warmup" | "-w" => config.warmup = crate::value(&mut args, &arg)?,
+                "--baseline" | "-b" => baseline = crate::value(&mut args, &arg)?,
+                "--save" => save = true,
+                "--threshold" | "-t" => threshold = crate::value(&mut args, &arg)?,
+                day => days.push(crate::parse_day(day)?),
+            }
+        }
+
+        if all {
+            days = crate::all_days();
+        }
+        if days.is_empty() {
+            return Err("no day given; pass a DAY or --all".to_string());
+        }
+
+        Ok(Self {
+            days,
+            all,
+            config,
+            baseline,
+            save,
+            threshold,
+        })
+    }
+}
+
+/// Benchmark the requested days, compare them to the saved baseline and optionally save them as the new baseline.
+pub fn bench(args: &Args) -> ExitCode {
+    let baseline = match fs::read_to_string(&args.baseline) {
+        Ok(json) => match bench::from_json(&json) {
+            Ok(baseline) => baseline,
+            Err(e) => {
+                eprintln!("ignoring baseline {}: {e}", args.baseline.display());
+                Vec::new()
+            }
+        },
+        Err(_) => Vec::new(),
+    };
+
+    let mut failed = false;
+    let mut measurements = Vec::new();
+    let mut regressions = 0;
+
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}  {}{:>9}",
+        "day",
+        "phase",
+        "min",
+        "median",
+        "p95",
+        "mean",
+        memory_column("allocs / bytes / peak"),
+        "baseline"
+    );
+    for &day in &args.days {
+        let result = registry::find(crate::year(), day).and_then(|entry| {
+            let input = aoc::read_input(entry.year, day, None)?;
+            entry.bench(&input, &args.config)
+        });
+        match result {
+            Ok(day_measurements) => {
+                for m in &day_measurements {
+                    let change = bench::change(m, &baseline);
+                    let regressed = change.is_some_and(|c| c * 100.0 > args.threshold);
+                    if regressed {
+                        regressions += 1;
+                    }
+                    print_row(m, change, regressed);
+                }
+                measurements.extend(day_measurements);
+            }
+            Err(Error::Input(InputError::Missing { .. } | InputError::Empty { .. }))
+                if args.all => {}
+            Err(e) => {
+                failed = true;
+                eprintln!("day {day}: {e}");
+            }
+        }
+    }
+
+    if regressions > 0 {
+        println!(
+            "{regressions} regression(s) of more than {}% against {}",
+            args.threshold,
+            args.baseline.display()
+        );
+    }
+
+    if args.save {
+        // Keep the baseline of days that were not benchmarked this time.
+        let mut saved: Vec<Measurement> = baseline
+            .into_iter()
+            .filter(|b| !args.days.contains(&b.day))
+            .chain(measurements)
+            .collect();
+        saved.sort_by_key(|m| m.day);
+        if let Err(e) = save(&args.baseline, &saved) {
+            eprintln!("saving baseline {} failed: {e}", args.baseline.display());
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_row(m: &Measurement, change: Option<f64>, regressed: bool) {
+    let change = change.map_or_else(String::new, |c| format!("{:+.1}%", c * 100.0));
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}  {}{:>9}{}",
+        m.day,
+        m.phase,
+        format!("{:.1?}", m.stats.min),
+        format!("{:.1?}", m.stats.median),
+        format!("{:.1?}", m.stats.p95),
+        format!("{:.1?}", m.stats.mean),
+        memory_column(m.stats.memory),
+        change,
+        if regressed { "  regression" } else { "" },
+    );
+}
+
+/// The memory column of a row, left out unless allocations are counted.
+fn memory_column(memory: impl std::fmt::Display) -> String {
+    if alloc::ENABLED {
+        format!("{memory:>24}  ")
+    } else {
+        String::new()
+    }
+}
+
+fn save(path: &Path, measurements: &[Measurement]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, format!("{}\n", bench::to_json(measurements)))
+}
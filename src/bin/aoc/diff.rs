@@ -0,0 +1,107 @@
+use aoc::{gen, input::InputError, registry, Error};
+use std::process::ExitCode;
+
+/// The arguments of the `diff` command.
+pub struct Args {
+    days: Vec<u8>,
+    /// The number of generated inputs per day.
+    seeds: u64,
+    /// The size of the generated inputs, in the units of the day's generator.
+    size: Option<usize>,
+}
+
+impl Args {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut days = Vec::new();
+        let mut seeds = 20;
+        let mut size = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => days = crate::all_days(),
+                "--seeds" => seeds = crate::value(&mut args, &arg)?,
+                "--size" | "-s" => size = Some(crate::value(&mut args, &arg)?),
+                day => days.push(crate::parse_day(day)?),
+            }
+        }
+
+        if days.is_empty() {
+            days = crate::all_days();
+        }
+
+        Ok(Self { days, seeds, size })
+    }
+}
+
+/// Run every alternative implementation of the requested days on the real input and on generated inputs, and print
+/// each disagreement with the main implementation along with the smallest input it could be shrunk to.
+///
+/// Days without alternatives are skipped. Fails when any implementations disagree.
+pub fn diff(args: &Args) -> ExitCode {
+    let mut failed = false;
+    for &day in &args.days {
+        let entry = match registry::find(crate::year(), day) {
+            Ok(entry) => entry,
+            Err(e) => {
+                failed = true;
+                eprintln!("day {day}: {e}");
+                continue;
+            }
+        };
+        let alternatives = entry.alternatives();
+        if alternatives.is_empty() {
+            continue;
+        }
+
+        let mut inputs = Vec::new();
+        match aoc::read_input(crate::year(), day, None) {
+            Ok(input) => inputs.push(input),
+            Err(Error::Input(InputError::Missing { .. } | InputError::Empty { .. })) => {}
+            Err(e) => {
+                failed = true;
+                eprintln!("day {day}: {e}");
+            }
+        }
+        if let Some(generator) = gen::find(crate::year(), day) {
+            let size = args.size.unwrap_or(generator.default_size);
+            inputs.extend((0..args.seeds).map(|seed| generator.generate(seed, size)));
+        }
+
+        let mut disagreements = 0;
+        for input in &inputs {
+            match entry.differential(input) {
+                Ok(found) => {
+                    for disagreement in &found {
+                        println!("{disagreement}");
+                    }
+                    disagreements += found.len();
+                }
+                Err(e) => {
+                    failed = true;
+                    eprintln!("day {day}: {e}");
+                }
+            }
+        }
+
+        let names: Vec<String> = alternatives
+            .iter()
+            .map(|(part, name)| format!("part {part} `{name}`"))
+            .collect();
+        if disagreements == 0 {
+            println!(
+                "day {day}: {} agree on {} inputs",
+                names.join(", "),
+                inputs.len()
+            );
+        } else {
+            failed = true;
+            println!("day {day}: {disagreements} disagreements");
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,51 @@
+use aoc::client::{Client, Fetched};
+use std::process::ExitCode;
+
+/// The arguments of the `fetch` command.
+pub struct Args {
+    days: Vec<u8>,
+}
+
+impl Args {
+    pub fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut days = Vec::new();
+
+        for arg in args {
+            match arg.as_str() {
+                "--all" => days = crate::all_days(),
+                day => days.push(crate::parse_day(day)?),
+            }
+        }
+
+        if days.is_empty() {
+            return Err("no day given; pass a DAY or --all".to_string());
+        }
+
+        Ok(Self { days })
+    }
+}
+
+/// Download the inputs of the requested days that are not on disk yet.
+pub fn fetch(args: &Args) -> ExitCode {
+    let client = Client::from_config(aoc::config::get());
+
+    let mut failed = false;
+    for &day in &args.days {
+        match client.fetch(day) {
+            Ok(Fetched::Cached(path)) => println!("day {day}: cached in {}", path.display()),
+            Ok(Fetched::Downloaded(path)) => {
+                println!("day {day}: downloaded to {}", path.display());
+            }
+            Err(e) => {
+                failed = true;
+                eprintln!("day {day}: {e}");
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
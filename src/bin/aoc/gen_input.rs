@@ -0,0 +1,51 @@
+use aoc::gen;
+use std::{
+    io::{self, Write},
+    process::ExitCode,
+};
+
+/// The arguments of the `gen-input` command.
+pub struct Args {
+    day: u8,
+    /// The size of the input, in the units of the day's generator.
+    size: Option<usize>,
+    seed: u64,
+}
+
+impl Args {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut size = None;
+        let mut seed = 0;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--size" | "-s" => size = Some(crate::value(&mut args, &arg)?),
+                "--seed" => seed = crate::value(&mut args, &arg)?,
+                value if day.is_none() => day = Some(crate::parse_day(value)?),
+                value => return Err(format!("unexpected argument: {value}")),
+            }
+        }
+
+        Ok(Self {
+            day: day.ok_or("no day given")?,
+            size,
+            seed,
+        })
+    }
+}
+
+/// Print a random input for the requested day, of the typical size of a real input unless `--size` says otherwise.
+pub fn gen_input(args: &Args) -> ExitCode {
+    let Some(generator) = gen::find(crate::year(), args.day) else {
+        eprintln!("day {}: no input generator", args.day);
+        return ExitCode::FAILURE;
+    };
+
+    let input = generator.generate(args.seed, args.size.unwrap_or(generator.default_size));
+    if let Err(e) = io::stdout().lock().write_all(input.as_bytes()) {
+        eprintln!("writing the input failed: {e}");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
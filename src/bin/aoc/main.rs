@@ -0,0 +1,253 @@
+//! # aoc
+//! A single runner for every day's [Solution](aoc::Solution).
+//!
+//! ```not_rust
+//! aoc run 4            # both parts of day 4
+//! aoc run 4 --part 2   # only part 2 of day 4
+//! aoc run --all        # every registered day
+//! aoc run 4 --input -  # day 4 with the input read from stdin
+//! aoc run --all -f json  # every registered day as JSON
+//! aoc run --all -j 4   # every registered day, four at a time
+//! aoc readme           # rewrite the status section of the README
+//! aoc readme --check   # fail when the status section is stale
+//! aoc bench 1 2        # benchmark days 1 and 2 against the saved baseline
+//! aoc bench --all --save
+//! aoc verify           # recompute every accepted answer in answers.toml
+//! aoc fetch 5          # download the input of day 5 unless it is already there
+//! aoc submit 5 -p 1    # submit the answer to part 1 of day 5
+//! aoc gen-input 4 --size 1000 --seed 7  # a random day 4 input with 1000 boards
+//! aoc diff 1 2         # compare the alternative implementations of days 1 and 2 with the main ones
+//! aoc run 4 -vv --trace-file trace.log  # day 4 with every step traced to trace.log
+//! aoc run --year 2022 4  # day 4 of 2022
+//! ```
+//!
+//! `-v` traces the milestones of each part and `-vv` every step, to stderr unless `--trace-file` is given.
+//!
+//! `--year` picks the year of the days of any command, which defaults to the `year` of `aoc.toml`. Days of other years
+//! than 2021 read their inputs from `y<YEAR>/day<N>/input.txt` in the input directory and keep their answers in
+//! `answers/<YEAR>.toml`.
+//!
+//! Inputs are read from `$AOC_INPUT_DIR/day<N>/input.txt` when the variable is set, from the `input_dir` of `aoc.toml`
+//! when that is, and from `src/day<N>/input.txt` otherwise. The other settings of [`aoc.toml`](aoc::config) are
+//! defaults for the options of the commands.
+#![deny(clippy::all, clippy::pedantic)]
+
+mod bench;
+mod diff;
+mod fetch;
+mod gen_input;
+mod readme;
+mod run;
+mod submit;
+mod verify;
+
+use aoc::{config, registry, trace};
+use std::{env, process::ExitCode};
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc::alloc::CountingAllocator = aoc::alloc::CountingAllocator;
+
+const USAGE: &str = "usage: aoc run <DAY> [--part <PART>] [--input <PATH|->] [--format <text|json|csv>]
+       aoc run --all [--part <PART>] [--format <text|json|csv>] [--jobs <N>]
+       aoc readme [--check]
+       aoc bench <DAY>... | --all [--iterations <N>] [--warmup <N>] [--baseline <PATH>] [--save] [--threshold <PERCENT>]
+       aoc verify [<DAY>... | --all] [--answers <PATH>]
+       aoc fetch <DAY>... | --all
+       aoc submit <DAY> --part <PART> [--answer <ANSWER>] [--input <PATH|->] [--answers <PATH>] [--guesses <PATH>]
+       aoc gen-input <DAY> [--size <N>] [--seed <SEED>]
+       aoc diff [<DAY>... | --all] [--seeds <N>] [--size <N>]
+
+Every command also takes --year <YEAR> to pick the year of the days, -v or -vv to trace the steps of the solutions,
+and --trace-file <PATH> to write the trace to PATH.";
+
+fn main() -> ExitCode {
+    let mut args = match global_options(env::args().skip(1)) {
+        Ok(args) => args.into_iter(),
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match args.next().as_deref() {
+        Some("run") => run::Args::parse(args).map(|args| run::run(&args)),
+        Some("readme") => match args.next().as_deref() {
+            None => Ok(readme::readme(false)),
+            Some("--check") => Ok(readme::readme(true)),
+            Some(arg) => Err(format!("unexpected argument: {arg}")),
+        },
+        Some("verify") => verify::Args::parse(args).map(|args| verify::verify(&args)),
+        Some("bench") => bench::Args::parse(args).map(|args| bench::bench(&args)),
+        Some("fetch") => fetch::Args::parse(args).map(|args| fetch::fetch(&args)),
+        Some("submit") => submit::Args::parse(args).map(|args| submit::submit(&args)),
+        Some("diff") => diff::Args::parse(args).map(|args| diff::diff(&args)),
+        Some("gen-input") => gen_input::Args::parse(args).map(|args| gen_input::gen_input(&args)),
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Some(command) => Err(format!("unknown command: {command}")),
+        None => Err("missing command".to_string()),
+    };
+
+    trace::flush();
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {e}\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Parse a day given on the command line.
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.parse().map_err(|_| format!("invalid day: {day}"))
+}
+
+/// The value of the option `name`, taken from the next argument.
+fn value<T: std::str::FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    name: &str,
+) -> Result<T, String> {
+    let value = args.next().ok_or(format!("{name} needs a value"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {name}: {value}"))
+}
+
+/// The options of the commands that take a value, which is never mistaken for an option that every command takes.
+///
+/// A test checks the parser of every command against this list.
+const TAKE_VALUES: &[&str] = &[
+    "--answer",
+    "--answers",
+    "-a",
+    "--baseline",
+    "-b",
+    "--format",
+    "-f",
+    "--guesses",
+    "-g",
+    "--input",
+    "-i",
+    "--iterations",
+    "-n",
+    "--jobs",
+    "-j",
+    "--part",
+    "-p",
+    "--seed",
+    "--seeds",
+    "--size",
+    "-s",
+    "--threshold",
+    "-t",
+    "--warmup",
+    "-w",
+    "--year",
+];
+
+/// Take the options that every command takes out of `args`, set up tracing and load the
+/// [`Config`](aoc::config::Config) with them, and return the other arguments.
+fn global_options(args: impl Iterator<Item = String>) -> Result<Vec<String>, String> {
+    let args = trace::configure(args, TAKE_VALUES).map_err(|e| e.to_string())?;
+
+    let mut rest = Vec::new();
+    let mut options = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => options.push(("year", args.next().ok_or("--year needs a value")?)),
+            option if TAKE_VALUES.contains(&option) => {
+                rest.push(arg);
+                rest.extend(args.next());
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    config::init_with(&options).map_err(|e| e.to_string())?;
+    Ok(rest)
+}
+
+/// The year of the days of the command.
+fn year() -> u16 {
+    config::get().year
+}
+
+/// Every registered day of the [year].
+fn all_days() -> Vec<u8> {
+    registry::days(year()).map(|e| e.day).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TAKE_VALUES;
+
+    /// Every option of a command, given without its value, to the parser of the command.
+    type Parse = fn(std::vec::IntoIter<String>) -> Result<(), String>;
+
+    /// The parser and the source of each command with options.
+    const COMMANDS: &[(Parse, &str)] = &[
+        (
+            |args| super::run::Args::parse(args).map(drop),
+            include_str!("run.rs"),
+        ),
+        (
+            |args| super::verify::Args::parse(args).map(drop),
+            include_str!("verify.rs"),
+        ),
+        (
+            |args| super::bench::Args::parse(args).map(drop),
+            include_str!("bench.rs"),
+        ),
+        (
+            |args| super::fetch::Args::parse(args).map(drop),
+            include_str!("fetch.rs"),
+        ),
+        (
+            |args| super::submit::Args::parse(args).map(drop),
+            include_str!("submit.rs"),
+        ),
+        (
+            |args| super::diff::Args::parse(args).map(drop),
+            include_str!("diff.rs"),
+        ),
+        (
+            |args| super::gen_input::Args::parse(args).map(drop),
+            include_str!("gen_input.rs"),
+        ),
+    ];
+
+    /// The string literals in `source` that look like options.
+    fn options(source: &str) -> impl Iterator<Item = &str> {
+        source
+            .split('"')
+            .skip(1)
+            .step_by(2)
+            .filter(|literal| literal.starts_with('-') && !literal.contains(' '))
+    }
+
+    #[test]
+    fn every_option_with_a_value_is_known() {
+        let mut used = Vec::new();
+        for (parse, source) in COMMANDS {
+            for option in options(source) {
+                if let Err(e) = parse(vec![option.to_string()].into_iter()) {
+                    if e.ends_with("needs a value") {
+                        assert!(
+                            TAKE_VALUES.contains(&option),
+                            "{option} is missing from TAKE_VALUES"
+                        );
+                        used.push(option);
+                    }
+                }
+            }
+        }
+        for option in TAKE_VALUES.iter().filter(|&&option| option != "--year") {
+            assert!(used.contains(option), "no command takes {option}");
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use aoc::{answers::Answers, registry, status};
+use std::{fs, path::Path, process::ExitCode};
+
+/// Rewrite the status section of the README with every registered year, or with `check` only report whether it is
+/// stale.
+pub fn readme(check: bool) -> ExitCode {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md");
+    let readme = match fs::read_to_string(&path) {
+        Ok(readme) => readme,
+        Err(e) => {
+            eprintln!("reading {} failed: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut statuses = Vec::new();
+    for year in registry::years() {
+        let answers = match Answers::load(&Answers::default_path(year)) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        };
+        statuses.extend(status::collect(year, &answers));
+    }
+    if check {
+        if status::is_current(&readme, &statuses) {
+            return ExitCode::SUCCESS;
+        }
+        eprintln!("{} is stale; run `aoc readme` to update it", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    if let Err(e) = fs::write(&path, status::rewrite(&readme, &statuses)) {
+        eprintln!("writing {} failed: {e}", path.display());
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,154 @@
+use aoc::{
+    input::InputError,
+    pool,
+    registry::{self, DayRun},
+    report::{self, Format, Row},
+    Error, Part,
+};
+use std::{
+    io::{self, Write},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+/// The arguments of the `run` command.
+pub struct Args {
+    days: Vec<u8>,
+    parts: Vec<Part>,
+    all: bool,
+    input: Option<String>,
+    format: Format,
+    /// The number of days run at the same time.
+    jobs: usize,
+}
+
+impl Args {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut days = Vec::new();
+        let mut parts = Part::ALL.to_vec();
+        let mut all = false;
+        let mut input = None;
+        let mut format = aoc::config::get().format;
+        let mut jobs = 1;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--part" | "-p" => {
+                    let part = args.next().ok_or("--part needs a value")?;
+                    parts = vec![Part::try_from(part.as_str())?];
+                }
+                "--input" | "-i" => input = Some(args.next().ok_or("--input needs a value")?),
+                "--format" | "-f" => {
+                    let value = args.next().ok_or("--format needs a value")?;
+                    format = Format::try_from(value.as_str())?;
+                }
+                "--jobs" | "-j" => jobs = crate::value(&mut args, &arg)?,
+                day => days.push(crate::parse_day(day)?),
+            }
+        }
+
+        if all {
+            days = crate::all_days();
+        }
+        if days.is_empty() {
+            return Err("no day given; pass a DAY or --all".to_string());
+        }
+        if input.is_some() && days.len() > 1 {
+            return Err("--input can only be used with a single day".to_string());
+        }
+        if jobs == 0 {
+            return Err("--jobs must be at least 1".to_string());
+        }
+        if jobs > 1 && aoc::alloc::ENABLED {
+            // The allocation counters are shared by every thread, so each day would be charged for the others.
+            return Err("--jobs must be 1 when counting allocations".to_string());
+        }
+
+        Ok(Self {
+            days,
+            parts,
+            all,
+            input,
+            format,
+            jobs,
+        })
+    }
+}
+
+/// Run the requested days on up to `--jobs` threads, print their results in day order in the requested [Format] and
+/// report whether every day parsed its input.
+///
+/// With `--all`, days without an input yet are skipped rather than failed. Errors that stop a whole day, including
+/// panics, go to stderr in the text format and into the results otherwise. When more than one day was run, the text
+/// format ends with the wall time of the whole run next to the wall times of those days added up, which the other
+/// formats write to stderr. std cannot measure CPU time, and the added up times only match it while every job has a
+/// core of its own.
+pub fn run(args: &Args) -> ExitCode {
+    let mut failed = false;
+
+    let start = Instant::now();
+    let done = pool::map(args.days.clone(), args.jobs, |day| {
+        run_day(day, &args.parts, args.input.as_deref())
+    });
+    let wall = start.elapsed();
+
+    let mut rows = Vec::new();
+    // The days that were run, leaving out those that --all skipped.
+    let mut ran = 0;
+    let mut busy = Duration::ZERO;
+    for (&day, done) in args.days.iter().zip(done) {
+        let elapsed = done.elapsed;
+        match done.result.unwrap_or_else(|p| Err(Error::Panicked(p.0))) {
+            Err(Error::Input(InputError::Missing { .. } | InputError::Empty { .. }))
+                if args.all =>
+            {
+                continue;
+            }
+            Ok(run) => rows.extend(Row::from_run(&run)),
+            Err(e) => {
+                failed = true;
+                if args.format == Format::Text {
+                    eprintln!("day {day}: {e}");
+                } else {
+                    rows.extend(Row::failed(crate::year(), day, &args.parts, &e));
+                }
+            }
+        }
+        ran += 1;
+        busy += elapsed;
+    }
+
+    let mut stdout = io::stdout().lock();
+    let written = report::write(args.format, &rows, &mut stdout).and_then(|()| {
+        if ran < 2 {
+            return Ok(());
+        }
+        let jobs = args.jobs.min(ran);
+        let summary = format!(
+            "{ran} days in {wall:?} wall time on {jobs} job{}, {busy:?} for the days added up",
+            if jobs == 1 { "" } else { "s" }
+        );
+        if args.format == Format::Text {
+            writeln!(stdout, "\n{summary}")
+        } else {
+            writeln!(io::stderr(), "{summary}")
+        }
+    });
+    if let Err(e) = written {
+        eprintln!("writing results failed: {e}");
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_day(day: u8, parts: &[Part], input: Option<&str>) -> aoc::Result<DayRun> {
+    let entry = registry::find(crate::year(), day)?;
+    let input = aoc::read_input(entry.year, day, input)?;
+    entry.solve(&input, parts)
+}
@@ -0,0 +1,99 @@
+use aoc::{
+    answers::Answers,
+    client::Client,
+    registry,
+    submit::{self, Guesses, Outcome},
+    Part,
+};
+use std::{path::PathBuf, process::ExitCode};
+
+/// The arguments of the `submit` command.
+pub struct Args {
+    day: u8,
+    part: Part,
+    /// The answer to submit instead of the computed one.
+    answer: Option<String>,
+    input: Option<String>,
+    answers: PathBuf,
+    guesses: PathBuf,
+}
+
+impl Args {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut part = None;
+        let mut answer = None;
+        let mut input = None;
+        let mut answers = Answers::default_path(crate::year());
+        let mut guesses = Guesses::default_path(crate::year());
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let value = args.next().ok_or("--part needs a value")?;
+                    part = Some(Part::try_from(value.as_str())?);
+                }
+                "--answer" => answer = Some(args.next().ok_or("--answer needs a value")?),
+                "--input" | "-i" => input = Some(args.next().ok_or("--input needs a value")?),
+                "--answers" | "-a" => answers = crate::value(&mut args, &arg)?,
+                "--guesses" | "-g" => guesses = crate::value(&mut args, &arg)?,
+                value if day.is_none() => day = Some(crate::parse_day(value)?),
+                value => return Err(format!("unexpected argument: {value}")),
+            }
+        }
+
+        Ok(Self {
+            day: day.ok_or("no day given")?,
+            part: part.ok_or("no part given; pass --part 1 or --part 2")?,
+            answer,
+            input,
+            answers,
+            guesses,
+        })
+    }
+}
+
+/// Compute the answer of the requested part and submit it, unless the accepted answers or earlier guesses show that
+/// it would be pointless.
+///
+/// A correct answer is recorded in the answer store and every other verdict in the guesses.
+pub fn submit(args: &Args) -> ExitCode {
+    match try_submit(args) {
+        Ok(Outcome::Correct) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("day {} part {}: {e}", args.day, args.part);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn try_submit(args: &Args) -> Result<Outcome, Box<dyn std::error::Error>> {
+    let answer = if let Some(answer) = &args.answer {
+        answer.clone()
+    } else {
+        let year = crate::year();
+        let input = aoc::read_input(year, args.day, args.input.as_deref())?;
+        let mut run = registry::find(year, args.day)?.solve(&input, &[args.part])?;
+        run.parts.remove(0).answer?.to_string()
+    };
+
+    let mut answers = Answers::load(&args.answers)?;
+    let mut guesses = Guesses::load(&args.guesses)?;
+    let result = submit::submit(
+        &Client::from_config(aoc::config::get()),
+        &mut answers,
+        &mut guesses,
+        args.day,
+        args.part,
+        &answer,
+    );
+    guesses.save(&args.guesses)?;
+    let outcome = result?;
+
+    if outcome == Outcome::Correct {
+        answers.save(&args.answers)?;
+    }
+    println!("day {} part {}: {answer} is {outcome}", args.day, args.part);
+    Ok(outcome)
+}
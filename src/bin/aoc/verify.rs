@@ -0,0 +1,84 @@
+use aoc::{
+    answers::{self, Answers, Verification},
+    input::InputError,
+    registry, Error,
+};
+use std::{path::PathBuf, process::ExitCode};
+
+/// The arguments of the `verify` command.
+pub struct Args {
+    days: Vec<u8>,
+    all: bool,
+    answers: PathBuf,
+}
+
+impl Args {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut days = Vec::new();
+        let mut all = false;
+        let mut answers = Answers::default_path(crate::year());
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--answers" | "-a" => answers = crate::value(&mut args, &arg)?,
+                day => days.push(crate::parse_day(day)?),
+            }
+        }
+
+        if all || days.is_empty() {
+            all = true;
+            days = crate::all_days();
+        }
+
+        Ok(Self { days, all, answers })
+    }
+}
+
+/// Recompute the answers of the requested days and compare them with the accepted answers.
+///
+/// Fails when any accepted answer is not reproduced. With `--all`, days without an input or an accepted answer are skipped.
+pub fn verify(args: &Args) -> ExitCode {
+    let answers = match Answers::load(&args.answers) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = false;
+    for &day in &args.days {
+        let has_answers = answers.all().iter().any(|(d, _, _)| *d == day);
+        let result = registry::find(crate::year(), day).and_then(|entry| {
+            let input = aoc::read_input(entry.year, day, None)?;
+            answers::verify(entry, &input, &answers)
+        });
+
+        match result {
+            Ok(verifications) => {
+                for (part, verification) in verifications {
+                    if matches!(
+                        verification,
+                        Verification::Mismatch { .. } | Verification::Failed(_)
+                    ) {
+                        failed = true;
+                    }
+                    println!("day {day} part {part}: {verification}");
+                }
+            }
+            Err(Error::Input(InputError::Missing { .. } | InputError::Empty { .. }))
+                if args.all && !has_answers => {}
+            Err(e) => {
+                failed = true;
+                eprintln!("day {day}: {e}");
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,337 @@
+//! A [Client] for the Advent of Code website that downloads puzzle inputs into the input directory.
+//!
+//! Requests are authenticated with the session cookie of a logged in browser, taken from `$AOC_SESSION` or the
+//! session file of the [`Config`]. Inputs never change, so a day that already has an input is never downloaded again,
+//! and requests are spaced at least [`MIN_INTERVAL`] apart to go easy on the server.
+use crate::{
+    config::Config,
+    http::{HttpError, Request, Response},
+    input,
+};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use thiserror::Error;
+
+/// The environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The environment variable overriding `base_url` in `aoc.toml`, which defaults to [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The least time between two requests to the server.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The website asks automated tools to say who they are.
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("no session token: set {SESSION_VAR} or write it to {}", .0.display())]
+    NoSession(PathBuf),
+    #[error("the session token was rejected; log in again and update it")]
+    BadSession,
+    #[error("the puzzle of day {0} is not available yet")]
+    NotAvailable(u8),
+    #[error("the server is rate limiting requests; try again later")]
+    RateLimited,
+    #[error("unexpected response with status {status}: {body}")]
+    Status { status: u16, body: String },
+    #[error(transparent)]
+    Http(#[from] HttpError),
+    #[error("reading {} failed", .path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("writing {} failed", .path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// Where a fetched input came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk at this path.
+    Cached(PathBuf),
+    /// The input was downloaded to this path.
+    Downloaded(PathBuf),
+}
+
+/// A [Client] for the Advent of Code website.
+#[derive(Debug, Clone)]
+pub struct Client {
+    /// The URL of the website, without a trailing slash.
+    pub base_url: String,
+    pub year: u16,
+    /// The session token, which is only needed once a request has to be made.
+    pub session: Option<String>,
+    /// The file the session token is read from, named when there is none.
+    pub session_file: PathBuf,
+    /// The directory inputs are cached in as `day<N>/input.txt`.
+    pub input_dir: PathBuf,
+    /// The least time between two requests, also across invocations.
+    pub min_interval: Duration,
+    /// The file recording the time of the last request.
+    pub throttle_file: PathBuf,
+}
+
+impl Client {
+    /// A [Client] for the website, year and input directory of `config`, with the session token from `$AOC_SESSION` or
+    /// the session file of `config`.
+    #[must_use]
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            base_url: config.base_url.clone(),
+            year: config.year,
+            session: load_session(env::var(SESSION_VAR).ok(), &config.session_file),
+            session_file: config.session_file.clone(),
+            input_dir: config.input_dir.clone(),
+            min_interval: MIN_INTERVAL,
+            throttle_file: Path::new(env!("CARGO_MANIFEST_DIR")).join("target/aoc-last-request"),
+        }
+    }
+
+    /// Make sure the input of `day` is on disk, downloading it unless it already is.
+    ///
+    /// # Errors
+    /// Returns a [`ClientError`] when the input has to be downloaded and the request fails or is refused, or when the
+    /// input cannot be written.
+    pub fn fetch(&self, day: u8) -> Result<Fetched, ClientError> {
+        let path = input::day_path(&self.input_dir, self.year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) if !input.trim().is_empty() => return Ok(Fetched::Cached(path)),
+            Ok(_) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(source) => return Err(ClientError::Read { path, source }),
+        }
+
+        let url = format!("{}/{}/day/{day}/input", self.base_url, self.year);
+        let response = self.send(&Request::get(&url))?;
+        match response.status {
+            200 => {}
+            404 => return Err(ClientError::NotAvailable(day)),
+            _ => return Err(unexpected(&response)),
+        }
+
+        let write = |source| ClientError::Write {
+            path: path.clone(),
+            source,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(write)?;
+        }
+        fs::write(&path, response.body).map_err(write)?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Send an authenticated `request` once [`min_interval`](Self::min_interval) has passed since the last one.
+    ///
+    /// # Errors
+    /// Returns a [`ClientError`] when there is no session token, the request fails, or the server refuses it for
+    /// reasons that apply to every request.
+    pub fn send(&self, request: &Request) -> Result<Response, ClientError> {
+        let session = self
+            .session
+            .as_deref()
+            .ok_or_else(|| ClientError::NoSession(self.session_file.clone()))?;
+        let request = request
+            .clone()
+            .header("Cookie", &format!("session={session}"))
+            .header("User-Agent", USER_AGENT);
+
+        self.throttle();
+        let response = request.send();
+        self.record_request()?;
+
+        let response = response?;
+        match response.status {
+            400 | 401 | 403 => Err(ClientError::BadSession),
+            429 => Err(ClientError::RateLimited),
+            _ => Ok(response),
+        }
+    }
+
+    /// Sleep until [`min_interval`](Self::min_interval) has passed since the request recorded in the
+    /// [`throttle_file`](Self::throttle_file).
+    fn throttle(&self) {
+        let Some(last) = fs::read_to_string(&self.throttle_file)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))
+        else {
+            return;
+        };
+        let elapsed = SystemTime::now()
+            .duration_since(last)
+            .unwrap_or(Duration::ZERO);
+        if let Some(wait) = self.min_interval.checked_sub(elapsed) {
+            thread::sleep(wait);
+        }
+    }
+
+    fn record_request(&self) -> Result<(), ClientError> {
+        let write = |source| ClientError::Write {
+            path: self.throttle_file.clone(),
+            source,
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+        if let Some(dir) = self.throttle_file.parent() {
+            fs::create_dir_all(dir).map_err(write)?;
+        }
+        fs::write(&self.throttle_file, now.as_millis().to_string()).map_err(write)
+    }
+}
+
+/// An error for a `response` that was not expected.
+pub(crate) fn unexpected(response: &Response) -> ClientError {
+    ClientError::Status {
+        status: response.status,
+        body: response.body.chars().take(200).collect(),
+    }
+}
+
+/// The default file the session token is read from when `$AOC_SESSION` is not set: `aoc/session` in
+/// `$XDG_CONFIG_HOME`, falling back to `~/.config`.
+#[must_use]
+pub fn session_path() -> PathBuf {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .unwrap_or_default();
+    config.join("aoc").join("session")
+}
+
+/// The session token from the environment `var`, or else from the `file`.
+fn load_session(var: Option<String>, file: &Path) -> Option<String> {
+    let token = |session: String| Some(session.trim().to_string()).filter(|s| !s.is_empty());
+    var.and_then(token)
+        .or_else(|| fs::read_to_string(file).ok().and_then(token))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{load_session, Client, ClientError, Fetched};
+    use crate::http::stub::Stub;
+    use std::{
+        env, fs,
+        path::PathBuf,
+        time::{Duration, Instant},
+    };
+
+    /// A [Client] for `url` that keeps its files in a fresh temporary directory named after the `test`.
+    pub(crate) fn client(url: &str, test: &str) -> (Client, PathBuf) {
+        let dir = env::temp_dir().join(format!("aoc-client-{test}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let client = Client {
+            base_url: url.to_string(),
+            year: 2021,
+            session: Some("token".to_string()),
+            session_file: dir.join("session"),
+            input_dir: dir.join("inputs"),
+            min_interval: Duration::ZERO,
+            throttle_file: dir.join("last-request"),
+        };
+        (client, dir)
+    }
+
+    #[test]
+    fn fetch_downloads_once() {
+        let stub = Stub::serve(|_| (200, "1\n2\n3\n".to_string()));
+        let (client, dir) = client(&stub.url, "once");
+        let path = dir.join("inputs/day3/input.txt");
+
+        assert_eq!(client.fetch(3).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+        assert_eq!(client.fetch(3).unwrap(), Fetched::Cached(path));
+        fs::remove_dir_all(&dir).unwrap();
+
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2021/day/3/input "));
+        assert!(requests[0].contains("\r\nCookie: session=token\r\n"));
+        assert!(requests[0].contains("\r\nUser-Agent: advent-of-code-2021/"));
+    }
+
+    #[test]
+    fn empty_input_is_refetched() {
+        let stub = Stub::serve(|_| (200, "input\n".to_string()));
+        let (client, dir) = client(&stub.url, "empty");
+        fs::create_dir_all(dir.join("inputs/day5")).unwrap();
+        fs::write(dir.join("inputs/day5/input.txt"), "").unwrap();
+
+        let fetched = client.fetch(5).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(fetched, Fetched::Downloaded(_)));
+    }
+
+    #[test]
+    fn requests_are_throttled() {
+        let stub = Stub::serve(|_| (200, "input\n".to_string()));
+        let (mut client, dir) = client(&stub.url, "throttle");
+        client.min_interval = Duration::from_millis(300);
+
+        let start = Instant::now();
+        client.fetch(1).unwrap();
+        client.fetch(2).unwrap();
+        let elapsed = start.elapsed();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(elapsed >= Duration::from_millis(300), "{elapsed:?}");
+    }
+
+    #[test]
+    fn refused_requests() {
+        let stub = Stub::serve(|request| {
+            if request.contains("/day/25/") {
+                (404, "Not Found".to_string())
+            } else {
+                (400, "Please log in".to_string())
+            }
+        });
+        let (mut client, dir) = client(&stub.url, "refused");
+
+        assert!(matches!(
+            client.fetch(25),
+            Err(ClientError::NotAvailable(25))
+        ));
+        assert!(matches!(client.fetch(1), Err(ClientError::BadSession)));
+        client.session = None;
+        assert!(matches!(client.fetch(1), Err(ClientError::NoSession(_))));
+        assert!(!dir.join("inputs/day1/input.txt").exists());
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(stub.requests().len(), 2);
+    }
+
+    #[test]
+    fn session_from_environment_or_file() {
+        let file = env::temp_dir().join(format!("aoc-session-{}", std::process::id()));
+        fs::write(&file, "from-file\n").unwrap();
+
+        assert_eq!(
+            load_session(Some("from-env".to_string()), &file).as_deref(),
+            Some("from-env")
+        );
+        assert_eq!(load_session(None, &file).as_deref(), Some("from-file"));
+        fs::remove_file(&file).unwrap();
+        assert_eq!(load_session(None, &file), None);
+    }
+}
@@ -0,0 +1,444 @@
+//! The project [Config], read from `aoc.toml` at the crate root by the runner, the generator and the [`Client`].
+//!
+//! Every setting has a default, which the file overrides, which an environment variable overrides in turn. Command
+//! line options of the binaries, such as `--format`, override all of them. A file setting every key looks like:
+//!
+//! ```toml
+//! year = 2021
+//! input_dir = "inputs"          # relative to aoc.toml
+//! session_file = "/home/me/.config/aoc/session"
+//! base_url = "https://adventofcode.com"
+//! format = "text"               # the default of `aoc run --format`
+//!
+//! [bench]
+//! warmup = 10                   # the default of `aoc bench --warmup`
+//! iterations = 100              # the default of `aoc bench --iterations`
+//! ```
+//!
+//! A missing file is the same as an empty one, but unknown keys and invalid values are errors that name the key.
+//!
+//! [`Client`]: crate::client::Client
+use crate::{
+    bench, client, input,
+    report::Format,
+    toml::{Document, TomlError, Value},
+    YEAR,
+};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("reading {} failed", .path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("{} is invalid: {source}", .path.display())]
+    Toml {
+        path: PathBuf,
+        #[source]
+        source: TomlError,
+    },
+    #[error("{}: unknown key `{key}`", .path.display())]
+    Unknown { path: PathBuf, key: String },
+    #[error("{}: `{key}` must be {expected}, got {value}", .path.display())]
+    Invalid {
+        path: PathBuf,
+        key: String,
+        expected: &'static str,
+        value: String,
+    },
+    #[error("${var} sets `{key}`, which must be {expected}, got {value:?}")]
+    Env {
+        var: &'static str,
+        key: &'static str,
+        expected: &'static str,
+        value: String,
+    },
+    #[error("--{option} must be {expected}, got {value:?}")]
+    Option {
+        option: String,
+        expected: &'static str,
+        value: String,
+    },
+}
+
+/// The settings shared by the binaries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The year of the puzzles.
+    pub year: u16,
+    /// The directory of `day<N>/input.txt` files.
+    pub input_dir: PathBuf,
+    /// The file the session token is read from when `$AOC_SESSION` is not set.
+    pub session_file: PathBuf,
+    /// The URL of the website, without a trailing slash.
+    pub base_url: String,
+    /// The output [Format] of `aoc run`.
+    pub format: Format,
+    /// The runs of `aoc bench`.
+    pub bench: bench::Config,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: YEAR,
+            input_dir: input::default_dir(),
+            session_file: client::session_path(),
+            base_url: client::DEFAULT_BASE_URL.to_string(),
+            format: Format::default(),
+            bench: bench::Config::default(),
+        }
+    }
+}
+
+/// A key of `aoc.toml`, with the environment variable overriding it.
+struct Setting {
+    key: &'static str,
+    var: &'static str,
+    /// Whether the value is an integer, which the variable holds in decimal.
+    integer: bool,
+}
+
+const SETTINGS: [Setting; 7] = [
+    Setting {
+        key: "year",
+        var: "AOC_YEAR",
+        integer: true,
+    },
+    Setting {
+        key: "input_dir",
+        var: input::INPUT_DIR_VAR,
+        integer: false,
+    },
+    Setting {
+        key: "session_file",
+        var: "AOC_SESSION_FILE",
+        integer: false,
+    },
+    Setting {
+        key: "base_url",
+        var: client::BASE_URL_VAR,
+        integer: false,
+    },
+    Setting {
+        key: "format",
+        var: "AOC_FORMAT",
+        integer: false,
+    },
+    Setting {
+        key: "bench.warmup",
+        var: "AOC_BENCH_WARMUP",
+        integer: true,
+    },
+    Setting {
+        key: "bench.iterations",
+        var: "AOC_BENCH_ITERATIONS",
+        integer: true,
+    },
+];
+
+impl Config {
+    /// The default location of the file, at the crate root.
+    #[must_use]
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.toml")
+    }
+
+    /// Load the [Config] from the file at `path` and the environment.
+    ///
+    /// # Errors
+    /// Returns a [`ConfigError`] when the file cannot be read, is not valid TOML, or has unknown keys or invalid values,
+    /// and when an environment variable has an invalid value.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        Self::load_with(path, |var| env::var(var).ok())
+    }
+
+    /// Load the [Config] from the file at `path` and the variables looked up with `var`.
+    fn load_with(path: &Path, var: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let mut config = Self::default();
+        match fs::read_to_string(path) {
+            Ok(toml) => config.merge(&toml, path)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(source) => {
+                return Err(ConfigError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        }
+
+        for setting in &SETTINGS {
+            let Some(text) = var(setting.var) else {
+                continue;
+            };
+            config
+                .set_text(setting, &text)
+                .map_err(|expected| ConfigError::Env {
+                    var: setting.var,
+                    key: setting.key,
+                    expected,
+                    value: text,
+                })?;
+        }
+
+        Ok(config)
+    }
+
+    /// Override the settings with command line `options`, given as `(key, value)` pairs such as `("year", "2022")`.
+    ///
+    /// # Errors
+    /// Returns ``Option`` when a value is invalid.
+    ///
+    /// # Panics
+    /// Panics when a key is not a setting.
+    pub fn apply(&mut self, options: &[(&str, String)]) -> Result<(), ConfigError> {
+        for (key, text) in options {
+            let setting = SETTINGS
+                .iter()
+                .find(|s| s.key == *key)
+                .unwrap_or_else(|| panic!("no setting `{key}`"));
+            self.set_text(setting, text)
+                .map_err(|expected| ConfigError::Option {
+                    option: key.replace(['.', '_'], "-"),
+                    expected,
+                    value: text.clone(),
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Set `setting` to the `text` of an environment variable or option, with relative paths taken from the working
+    /// directory.
+    fn set_text(&mut self, setting: &Setting, text: &str) -> Result<(), &'static str> {
+        let value = match text.trim().parse() {
+            Ok(n) if setting.integer => Value::Integer(n),
+            _ => Value::String(text.to_string()),
+        };
+        self.set(setting.key, &value, Path::new(""))
+    }
+
+    /// Override the settings in the `toml` of the file at `path`.
+    fn merge(&mut self, toml: &str, path: &Path) -> Result<(), ConfigError> {
+        let document = Document::parse(toml).map_err(|source| ConfigError::Toml {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let unknown = |key: String| ConfigError::Unknown {
+            path: path.to_path_buf(),
+            key,
+        };
+        let dir = path.parent().unwrap_or(Path::new(""));
+
+        for table in &document.tables {
+            if !(table.name.is_empty() || table.name == "bench") {
+                return Err(unknown(table.name.clone()));
+            }
+            for (key, value) in &table.entries {
+                let key = match table.name.as_str() {
+                    "" => key.clone(),
+                    table => format!("{table}.{key}"),
+                };
+                if !SETTINGS.iter().any(|s| s.key == key) {
+                    return Err(unknown(key));
+                }
+                self.set(&key, value, dir)
+                    .map_err(|expected| ConfigError::Invalid {
+                        path: path.to_path_buf(),
+                        key,
+                        expected,
+                        value: value.to_string(),
+                    })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set the setting `key` to `value`, with relative paths taken from `dir`, or return what the value must be.
+    fn set(&mut self, key: &str, value: &Value, dir: &Path) -> Result<(), &'static str> {
+        let string = || match value {
+            Value::String(s) if !s.trim().is_empty() => Some(s.trim()),
+            _ => None,
+        };
+        let integer = |min: i64| match value {
+            Value::Integer(n) if *n >= min => Some(*n),
+            _ => None,
+        };
+
+        match key {
+            "year" => {
+                self.year = integer(crate::FIRST_YEAR.into())
+                    .and_then(|y| y.try_into().ok())
+                    .ok_or("a year from 2015 on")?;
+            }
+            "input_dir" => self.input_dir = dir.join(string().ok_or("a path")?),
+            "session_file" => self.session_file = dir.join(string().ok_or("a path")?),
+            "base_url" => {
+                let url = string()
+                    .filter(|url| url.starts_with("http://") || url.starts_with("https://"))
+                    .ok_or("an http:// or https:// URL")?;
+                self.base_url = url.trim_end_matches('/').to_string();
+            }
+            "format" => {
+                self.format = string()
+                    .and_then(|f| Format::try_from(f).ok())
+                    .ok_or("text, json or csv")?;
+            }
+            "bench.warmup" => {
+                self.bench.warmup = integer(0)
+                    .and_then(|n| n.try_into().ok())
+                    .ok_or("a number of runs")?;
+            }
+            "bench.iterations" => {
+                self.bench.iterations = integer(1)
+                    .and_then(|n| n.try_into().ok())
+                    .ok_or("a number of runs of at least 1")?;
+            }
+            key => unreachable!("no setting `{key}`"),
+        }
+        Ok(())
+    }
+}
+
+static CURRENT: OnceLock<Config> = OnceLock::new();
+
+/// Load the project's [Config] from [`Config::default_path`] and the environment, unless it already was.
+///
+/// The binaries call this first, so that an invalid config is reported before [get] needs it.
+///
+/// # Errors
+/// Returns the errors of [`Config::load`].
+pub fn init() -> Result<&'static Config, ConfigError> {
+    init_with(&[])
+}
+
+/// Load the project's [Config] like [init], with the command line `options` of [`Config::apply`] overriding the file
+/// and the environment.
+///
+/// # Errors
+/// Returns the errors of [`Config::load`] and [`Config::apply`].
+pub fn init_with(options: &[(&str, String)]) -> Result<&'static Config, ConfigError> {
+    if let Some(config) = CURRENT.get() {
+        return Ok(config);
+    }
+    let mut config = Config::load(&Config::default_path())?;
+    config.apply(options)?;
+    Ok(CURRENT.get_or_init(|| config))
+}
+
+/// The project's [Config], which is loaded on first use unless [init] loaded it already.
+///
+/// This is for the binaries, which call [init] first. The library uses [init] itself, so that an invalid config comes
+/// back as an error.
+///
+/// # Panics
+/// Panics when the config is invalid and [init] has not reported it yet.
+#[must_use]
+pub fn get() -> &'static Config {
+    CURRENT.get_or_init(|| Config::load(&Config::default_path()).unwrap_or_else(|e| panic!("{e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, ConfigError};
+    use crate::report::Format;
+    use std::{env, fs, path::PathBuf};
+
+    /// Write `toml` to a fresh file named after the `test`.
+    fn file(test: &str, toml: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-config-{test}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("aoc.toml");
+        fs::write(&path, toml).unwrap();
+        path
+    }
+
+    #[test]
+    fn environment_overrides_file_overrides_defaults() {
+        let path = file(
+            "precedence",
+            "year = 2020\ninput_dir = \"inputs\"\nformat = \"json\"\n\n[bench]\niterations = 5\n",
+        );
+        let var = |var: &str| match var {
+            "AOC_YEAR" => Some("2019".to_string()),
+            "AOC_BASE_URL" => Some("http://localhost:8080/".to_string()),
+            _ => None,
+        };
+        let config = Config::load_with(&path, var).unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(config.year, 2019);
+        assert_eq!(config.input_dir, path.with_file_name("inputs"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.format, Format::Json);
+        assert_eq!(config.bench.iterations, 5);
+        assert_eq!(config.bench.warmup, Config::default().bench.warmup);
+
+        let missing = Config::load_with(&path, |_| None).unwrap();
+        assert_eq!(missing, Config::default());
+    }
+
+    #[test]
+    fn errors_name_the_key() {
+        let error = |toml: &str| {
+            let path = file("errors", toml);
+            let e = Config::load_with(&path, |_| None).unwrap_err();
+            fs::remove_dir_all(path.parent().unwrap()).unwrap();
+            e
+        };
+
+        let e = error("[bench]\niterations = 0\n");
+        assert!(matches!(&e, ConfigError::Invalid { key, .. } if key == "bench.iterations"));
+        assert!(e
+            .to_string()
+            .ends_with("`bench.iterations` must be a number of runs of at least 1, got 0"));
+        assert!(matches!(
+            error("formats = \"csv\"\n"),
+            ConfigError::Unknown { key, .. } if key == "formats"
+        ));
+        assert!(matches!(
+            error("[benchmark]\n"),
+            ConfigError::Unknown { key, .. } if key == "benchmark"
+        ));
+        assert!(matches!(
+            error("year = \"2021\"\n"),
+            ConfigError::Invalid { key, .. } if key == "year"
+        ));
+        assert!(matches!(error("year 2021\n"), ConfigError::Toml { .. }));
+
+        let e = Config::load_with(&PathBuf::from("no-such-aoc.toml"), |var| {
+            (var == "AOC_FORMAT").then(|| "yaml".to_string())
+        })
+        .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "$AOC_FORMAT sets `format`, which must be text, json or csv, got \"yaml\""
+        );
+    }
+
+    #[test]
+    fn options_override_everything() {
+        let mut config = Config::load_with(&PathBuf::from("no-such-aoc.toml"), |var| {
+            (var == "AOC_YEAR").then(|| "2019".to_string())
+        })
+        .unwrap();
+        config.apply(&[("year", "2022".to_string())]).unwrap();
+        assert_eq!(config.year, 2022);
+
+        let e = config
+            .apply(&[("bench.iterations", "many".to_string())])
+            .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "--bench-iterations must be a number of runs of at least 1, got \"many\""
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! # Day 1: Sonar Sweep
+//! You're minding your own business on a ship at sea when the overboard alarm goes off! You rush to see if you can help. Apparently, one of the Elves tripped and accidentally sent the sleigh keys flying into the ocean!
+//!
+//! Before you know it, you're inside a submarine the Elves keep ready for situations like this. It's covered in Christmas lights (because of course it is), and it even has an experimental antenna that should be able to track the keys if you can boost its signal strength high enough; there's a little meter that indicates the antenna's signal strength by displaying 0-50 stars.
+//!
+//! Your instincts tell you that in order to save Christmas, you'll need to get all fifty stars by December 25th.
+//!
+//! Collect stars by solving puzzles. Two puzzles will be made available on each day in the Advent calendar; the second puzzle is unlocked when you complete the first. Each puzzle grants one star. Good luck!
+//!
+//! As the submarine drops below the surface of the ocean, it automatically performs a sonar sweep of the nearby sea floor. On a small screen, the sonar sweep [Report] (your puzzle input) appears: each line is a measurement of the sea floor [Depth] as the sweep looks further and further away from the submarine.
+//!
+//! For example, suppose you had the following [Report]:
+//!
+//! ```not_rust example part1:7 part2:5
+//! 199
+//! 200
+//! 208
+//! 210
+//! 200
+//! 207
+//! 240
+//! 269
+//! 260
+//! 263
+//! ```
+//! This [Report] indicates that, scanning outward from the submarine, the sonar sweep found [Depth]s of 199, 200, 208, 210, and so on.
+//!
+//! The first order of business is to figure out how quickly the [Depth] increases, just so you know what you're dealing with - you never know if the keys will get carried into deeper water by an ocean current or a fish or something.
+//!
+//! To do this, count the number of times a [Depth] measurement increases from the previous measurement. (There is no measurement before the first measurement.) In the example above, the changes are as follows:
+//!
+//! ```not_rust
+//! 199 (N/A - no previous measurement)
+//! 200 (increased)
+//! 208 (increased)
+//! 210 (increased)
+//! 200 (decreased)
+//! 207 (increased)
+//! 240 (increased)
+//! 269 (increased)
+//! 260 (decreased)
+//! 263 (increased)
+//! ```
+//! In this example, there are 7 measurements that are larger than the previous measurement.
+//!
+//! How many measurements are larger than the previous measurement?
+//!
+//! # Part Two
+//! Considering every single measurement isn't as useful as you expected: there's just too much noise in the data.
+//!
+//! Instead, consider sums of a three-measurement sliding window. Again considering the above example:
+//! ```not_rust
+//! 199  A      
+//! 200  A B    
+//! 208  A B C  
+//! 210    B C D
+//! 200  E   C D
+//! 207  E F   D
+//! 240  E F G  
+//! 269    F G H
+//! 260      G H
+//! 263        H
+//! ```
+//! Start by comparing the first and second three-measurement windows. The measurements in the first window are marked A (199, 200, 208); their sum is 199 + 200 + 208 = 607. The second window is marked B (200, 208, 210); its sum is 618. The sum of measurements in the second window is larger than the sum of the first, so this first comparison increased.
+//!
+//! Your goal now is to count the number of times the sum of measurements in this sliding window increases from the previous sum. So, compare A with B, then compare B with C, then C with D, and so on. Stop when there aren't enough measurements left to create a new three-measurement sum.
+//!
+//! In the above example, the sum of each three-measurement window is as follows:
+//!
+//! ```not_rust
+//! A: 607 (N/A - no previous sum)
+//! B: 618 (increased)
+//! C: 618 (no change)
+//! D: 617 (decreased)
+//! E: 647 (increased)
+//! F: 716 (increased)
+//! G: 769 (increased)
+//! H: 792 (increased)
+//! ```
+//! In this example, there are 5 sums that are larger than the previous sum.
+//!
+//! Consider sums of a three-measurement sliding window. How many sums are larger than the previous sum?
+use crate::{Error, Implementation, ParseError, Part, Solution};
+use thiserror::Error;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const SOURCE: &'static str = include_str!("mod.rs");
+
+    type Input = Report;
+    type Answer = usize;
+
+    fn parse(input: &str) -> crate::Result<Self::Input> {
+        Ok(Report::try_from(input)?)
+    }
+
+    fn part1(report: &Self::Input) -> crate::Result<Self::Answer> {
+        Ok(report.num_increases(1)?)
+    }
+
+    fn part2(report: &Self::Input) -> crate::Result<Self::Answer> {
+        Ok(report.num_increases(3)?)
+    }
+
+    const ALTERNATIVES: &'static [Implementation<Self>] = &[
+        Implementation {
+            name: "sums",
+            part: Part::One,
+            solve: |report| Ok(report.num_sum_increases(1)?),
+        },
+        Implementation {
+            name: "sums",
+            part: Part::Two,
+            solve: |report| Ok(report.num_sum_increases(3)?),
+        },
+    ];
+}
+
+/// A sonar sweep measurement of [Depth].
+pub type Depth = i64;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ReportError {
+    #[error("window size must be positive")]
+    WindowSizeZero,
+    #[error(transparent)]
+    ParsingError(#[from] ParseError),
+}
+
+impl From<ReportError> for Error {
+    fn from(e: ReportError) -> Self {
+        match e {
+            ReportError::WindowSizeZero => Error::Solve(Box::new(e)),
+            ReportError::ParsingError(e) => e.into(),
+        }
+    }
+}
+
+/// The sonar sweep [Report] contains measurements of the sea floor [Depth] as the sweep looks further and further away from the submarine.
+#[derive(Debug, PartialEq)]
+pub struct Report {
+    measurements: Vec<Depth>,
+}
+
+impl Report {
+    /// Count the number of times a [Depth] measurement increases from the previous `window_size` measurements.
+    ///
+    /// # Errors
+    /// Returns a ``WindowSizeZero`` when the `window_size` is 0.
+    pub fn num_increases(&self, window_size: usize) -> Result<usize, ReportError> {
+        if window_size == 0 {
+            return Err(ReportError::WindowSizeZero);
+        }
+
+        let mut num = 0;
+        if self.measurements.len() > window_size {
+            for i in window_size..self.measurements.len() {
+                if (self.measurements[i] - self.measurements[i - window_size]) > 0 {
+                    num += 1;
+                }
+            }
+        }
+
+        Ok(num)
+    }
+
+    /// Count the number of times the sum of a sliding window of `window_size` measurements increases, the way the
+    /// puzzle describes it.
+    ///
+    /// This is slower than [`num_increases`](Self::num_increases), which only compares the measurements that differ
+    /// between two neighbouring windows, but obviously right.
+    ///
+    /// # Errors
+    /// Returns a ``WindowSizeZero`` when the `window_size` is 0.
+    pub fn num_sum_increases(&self, window_size: usize) -> Result<usize, ReportError> {
+        if window_size == 0 {
+            return Err(ReportError::WindowSizeZero);
+        }
+
+        let sums: Vec<Depth> = self
+            .measurements
+            .windows(window_size)
+            .map(|w| w.iter().sum())
+            .collect();
+        Ok(sums.windows(2).filter(|pair| pair[1] > pair[0]).count())
+    }
+}
+
+impl TryFrom<&str> for Report {
+    type Error = ReportError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut measurements = Vec::new();
+
+        for number in value.lines() {
+            let measurement = number
+                .parse::<Depth>()
+                .map_err(|_| ParseError::new(value, number, "a depth"))?;
+            measurements.push(measurement);
+        }
+
+        Ok(Self { measurements })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day1, Report, ReportError};
+    use crate::examples::{assert_examples, input};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day1>();
+    }
+
+    #[test]
+    fn number_of_increases_window_size_1() {
+        let report = Report::try_from(input::<Day1>().as_str()).unwrap();
+        let window_size = 1;
+        assert_eq!(report.num_increases(window_size).unwrap(), 7);
+    }
+
+    #[test]
+    fn number_of_increases_window_size_3() {
+        let report = Report::try_from(input::<Day1>().as_str()).unwrap();
+
+        let window_size = 3;
+        assert_eq!(report.num_increases(window_size).unwrap(), 5);
+    }
+
+    #[test]
+    fn bad_window_size() {
+        let report = Report::try_from(input::<Day1>().as_str()).unwrap();
+
+        let window_size = 0;
+        assert_eq!(
+            report.num_increases(window_size),
+            Err(ReportError::WindowSizeZero)
+        );
+    }
+
+    #[test]
+    fn bad_report() {
+        match Report::try_from("199\na,b,c") {
+            Err(ReportError::ParsingError(e)) => {
+                assert_eq!((e.line, e.column, e.found.as_str()), (2, 1, "a,b,c"));
+            }
+            _ => panic!("should have a parsing error"),
+        }
+    }
+}
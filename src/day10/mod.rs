@@ -0,0 +1,25 @@
+//! # Day 10
+//! <https://adventofcode.com/2021/day/10>
+use crate::{Result, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const SOURCE: &'static str = include_str!("mod.rs");
+
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn it_works() {
+        assert_eq!(1, 1);
+    }
+}
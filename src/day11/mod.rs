@@ -0,0 +1,25 @@
+//! # Day 11
+//! <https://adventofcode.com/2021/day/11>
+use crate::{Result, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const SOURCE: &'static str = include_str!("mod.rs");
+
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn it_works() {
+        assert_eq!(1, 1);
+    }
+}
@@ -1,22 +1,5 @@
-#![deny(clippy::all, clippy::pedantic)]
+use std::process::ExitCode;
 
-use std::{
-    fs::File,
-    io::{self, Read},
-};
-
-fn main() -> io::Result<()> {
-    let mut f = File::open("src/day1/input.txt")?;
-    let mut buf = String::new();
-    f.read_to_string(&mut buf)?;
-
-    Ok(())
+fn main() -> ExitCode {
+    aoc::run::<aoc::day12::Day12>()
 }
-
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn it_works() {
-        assert_eq!(1, 1)
-    }
-}
\ No newline at end of file
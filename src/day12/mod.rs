@@ -0,0 +1,25 @@
+//! # Day 12
+//! <https://adventofcode.com/2021/day/12>
+use crate::{Result, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const SOURCE: &'static str = include_str!("mod.rs");
+
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn it_works() {
+        assert_eq!(1, 1);
+    }
+}
@@ -0,0 +1,282 @@
+//! # Day 2: Dive!
+//! Now, you need to figure out how to pilot this thing.
+//!
+//! It seems like the [Submarine] can take a series of [Command]s like forward 1, down 2, or up 3:
+//!
+//! - forward X increases the horizontal position by X [Unit]s.
+//! - down X increases the depth by X [Unit]s.
+//! - up X decreases the depth by X [Unit]s.
+//!
+//! Note that since you're on a [Submarine], down and up affect your depth, and so they have the opposite result of what you might expect.
+//!
+//! The [Submarine] seems to already have a planned course (your puzzle input). You should probably figure out where it's going. For example:
+//! ```not_rust example part1:150 part2:900
+//! forward 5
+//! down 5
+//! forward 8
+//! up 3
+//! down 8
+//! forward 2
+//! ```
+//! Your horizontal position and depth both start at 0. The steps above would then modify them as follows:
+//!
+//! - forward 5 adds 5 to your horizontal position, a total of 5.
+//! - down 5 adds 5 to your depth, resulting in a value of 5.
+//! - forward 8 adds 8 to your horizontal position, a total of 13.
+//! - up 3 decreases your depth by 3, resulting in a value of 2.
+//! - down 8 adds 8 to your depth, resulting in a value of 10.
+//! - forward 2 adds 2 to your horizontal position, a total of 15.
+//!
+//! After following these instructions, you would have a horizontal position of 15 and a depth of 10. (Multiplying these together produces 150.)
+//!
+//! Calculate the horizontal position and depth you would have after following the planned course. What do you get if you multiply your final horizontal position by your final depth?
+//!
+//! # Part Two
+//! Based on your calculations, the planned course doesn't seem to make any sense. You find the [Submarine] manual and discover that the process is actually slightly more complicated.
+//!
+//! In addition to horizontal position and depth, you'll also need to track a third value, aim, which also starts at 0. The [Command]s also mean something entirely different than you first thought:
+//!
+//! - down X increases your aim by X [Unit]s.
+//! - up X decreases your aim by X [Unit]s.
+//! - forward X does two things:
+//!   - It increases your horizontal position by X [Unit]s.
+//!   - It increases your depth by your aim multiplied by X.
+//!
+//! Again note that since you're on a [Submarine], down and up do the opposite of what you might expect: "down" means aiming in the positive direction.
+//!
+//! Now, the above example does something different:
+//!
+//! - forward 5 adds 5 to your horizontal position, a total of 5. Because your aim is 0, your depth does not change.
+//! - down 5 adds 5 to your aim, resulting in a value of 5.
+//! - forward 8 adds 8 to your horizontal position, a total of 13. Because your aim is 5, your depth increases by 8*5=40.
+//! - up 3 decreases your aim by 3, resulting in a value of 2.
+//! - down 8 adds 8 to your aim, resulting in a value of 10.
+//! - forward 2 adds 2 to your horizontal position, a total of 15. Because your aim is 10, your depth increases by 2*10=20 to a total of 60.
+//!
+//! After following these new instructions, you would have a horizontal position of 15 and a depth of 60. (Multiplying these produces 900.)
+//!
+//! Using this new interpretation of the [Command]s, calculate the horizontal position and depth you would have after following the planned course. What do you get if you multiply your final horizontal position by your final depth?
+
+use crate::{Implementation, ParseError, Part, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const SOURCE: &'static str = include_str!("mod.rs");
+
+    type Input = Vec<Command>;
+    type Answer = Unit;
+
+    fn parse(input: &str) -> crate::Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, c)| Ok(Command::try_from(c).map_err(|e| e.shifted(i))?))
+            .collect()
+    }
+
+    fn part1(commands: &Self::Input) -> crate::Result<Self::Answer> {
+        let mut submarine = Submarine::<Naive>::default();
+        submarine.run_instructions(commands);
+        Ok(submarine.depth * submarine.distance)
+    }
+
+    fn part2(commands: &Self::Input) -> crate::Result<Self::Answer> {
+        let mut submarine = Submarine::<Complicated>::default();
+        submarine.run_instructions(commands);
+        Ok(submarine.depth * submarine.distance)
+    }
+
+    const ALTERNATIVES: &'static [Implementation<Self>] = &[
+        Implementation {
+            name: "totals",
+            part: Part::One,
+            solve: |commands| Ok(totals(commands)),
+        },
+        Implementation {
+            name: "fold",
+            part: Part::Two,
+            solve: |commands| Ok(fold(commands)),
+        },
+    ];
+}
+
+/// Part 1 from the total of each kind of [Command], since their order does not matter without aim.
+fn totals(commands: &[Command]) -> Unit {
+    let [mut forward, mut down, mut up] = [0; 3];
+    for &c in commands {
+        match c {
+            Command::Forward(n) => forward += n,
+            Command::Down(n) => down += n,
+            Command::Up(n) => up += n,
+        }
+    }
+    forward * (down - up)
+}
+
+/// Part 2 as a single fold over the `(distance, depth, aim)` of the [Submarine].
+fn fold(commands: &[Command]) -> Unit {
+    let (distance, depth, _) =
+        commands
+            .iter()
+            .fold((0, 0, 0), |(distance, depth, aim), &c| match c {
+                Command::Forward(n) => (distance + n, depth + aim * n, aim),
+                Command::Down(n) => (distance, depth, aim + n),
+                Command::Up(n) => (distance, depth, aim - n),
+            });
+    distance * depth
+}
+
+/// A [Unit] of both position and depth of the [Submarine].
+pub type Unit = i64;
+
+pub trait Operate {
+    fn operate(&mut self, c: Command);
+}
+
+#[derive(Default)]
+pub struct Naive;
+
+#[derive(Default)]
+pub struct Complicated;
+
+#[derive(Default)]
+pub struct Submarine<Calculation> {
+    distance: Unit,
+    depth: Unit,
+    aim: Unit,
+    phantom: std::marker::PhantomData<Calculation>,
+}
+
+impl<Calculation> Submarine<Calculation>
+where
+    Self: Operate,
+{
+    fn run_instructions(&mut self, commands: &[Command]) {
+        for (step, c) in commands.iter().enumerate() {
+            self.operate(*c);
+            crate::event!(
+                Trace,
+                "operate",
+                step = step + 1,
+                command = c,
+                distance = self.distance,
+                depth = self.depth,
+                aim = self.aim,
+            );
+        }
+        crate::event!(
+            Debug,
+            "arrived",
+            distance = self.distance,
+            depth = self.depth
+        );
+    }
+}
+
+impl Operate for Submarine<Naive> {
+    fn operate(&mut self, c: Command) {
+        match c {
+            Command::Forward(n) => self.distance += n,
+            Command::Down(n) => self.depth += n,
+            Command::Up(n) => self.depth -= n,
+        }
+    }
+}
+
+impl Operate for Submarine<Complicated> {
+    fn operate(&mut self, c: Command) {
+        match c {
+            Command::Forward(n) => {
+                self.distance += n;
+                self.depth += self.aim * n;
+            }
+            Command::Down(n) => self.aim += n,
+            Command::Up(n) => self.aim -= n,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Forward(Unit),
+    Down(Unit),
+    Up(Unit),
+}
+
+impl TryFrom<&str> for Command {
+    type Error = ParseError;
+
+    /// Parse a single line such as `forward 5`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let Some((command, amount)) = value.split_once(' ') else {
+            return Err(ParseError::at(
+                value,
+                value.len(),
+                0,
+                "a command followed by an amount",
+            ));
+        };
+        let amount = amount
+            .parse()
+            .map_err(|_| ParseError::new(value, amount, "an amount"))?;
+        match command {
+            "forward" => Ok(Command::Forward(amount)),
+            "down" => Ok(Command::Down(amount)),
+            "up" => Ok(Command::Up(amount)),
+            c => Err(ParseError::new(value, c, "`forward`, `down` or `up`")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Command, Complicated, Day2, Naive, Submarine};
+    use crate::{
+        examples::{assert_examples, input},
+        Solution,
+    };
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day2>();
+    }
+
+    #[test]
+    fn test_submarine_commands_naive() {
+        let mut submarine = Submarine::<Naive>::default();
+        submarine.run_instructions(&Day2::parse(&input::<Day2>()).unwrap());
+
+        assert_eq!(submarine.depth * submarine.distance, 150);
+    }
+
+    #[test]
+    fn test_submarine_commands_complicated() {
+        let mut submarine = Submarine::<Complicated>::default();
+        submarine.run_instructions(&Day2::parse(&input::<Day2>()).unwrap());
+
+        assert_eq!(submarine.depth * submarine.distance, 900);
+    }
+
+    #[test]
+    fn bad_commands() {
+        assert_eq!(Command::try_from("up 3"), Ok(Command::Up(3)));
+
+        let e = Day2::parse("forward 5\nbackward 2")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            e,
+            "parsing input failed: line 2, column 1: expected `forward`, `down` or `up`, found `backward`
+  |
+2 | backward 2
+  | ^^^^^^^^"
+        );
+
+        let e = Command::try_from("forward x").unwrap_err();
+        assert_eq!((e.column, e.found.as_str()), (9, "x"));
+        let e = Command::try_from("forward").unwrap_err();
+        assert_eq!((e.column, e.found.as_str()), (8, ""));
+    }
+}
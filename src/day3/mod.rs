@@ -0,0 +1,169 @@
+//! # Day 3: Binary Diagnostic
+//! The submarine's diagnostic report (your puzzle input) is a list of binary numbers of equal length.
+//!
+//! The gamma rate is built from the most common bit in each position of the numbers, and the epsilon rate from the
+//! least common bit. The power consumption is the gamma rate multiplied by the epsilon rate. For example:
+//!
+//! ```not_rust example part1:198 part2:230
+//! 00100
+//! 11110
+//! 10110
+//! 10111
+//! 10101
+//! 01111
+//! 00111
+//! 11100
+//! 10000
+//! 11001
+//! 00010
+//! 01010
+//! ```
+//! The most common bits give a gamma rate of `10110`, or 22, and the least common bits an epsilon rate of `01001`,
+//! or 9, so the power consumption is 198.
+//!
+//! What is the power consumption of the submarine?
+//!
+//! # Part Two
+//! The life support rating is the oxygen generator rating multiplied by the CO2 scrubber rating. Both are found by
+//! filtering the numbers one bit position at a time: the oxygen generator keeps the numbers with the most common bit
+//! in that position (`1` on a tie) and the CO2 scrubber the ones with the least common bit (`0` on a tie), until a
+//! single number is left.
+//!
+//! In the example above the oxygen generator rating is `10111`, or 23, and the CO2 scrubber rating is `01010`, or 10,
+//! so the life support rating is 230.
+//!
+//! What is the life support rating of the submarine?
+use crate::{ParseError, Result, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const SOURCE: &'static str = include_str!("mod.rs");
+
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        validate(input)?;
+        Ok(input.to_string())
+    }
+
+    fn part1(report: &Self::Input) -> Result<Self::Answer> {
+        let (gamma_rate, epsilon_rate) = calculate_rates(report);
+        Ok(gamma_rate * epsilon_rate)
+    }
+}
+
+/// Check that every line of the `report` is a binary number of the same width.
+fn validate(report: &str) -> std::result::Result<(), ParseError> {
+    let mut width = None;
+    for line in report.lines() {
+        if let Some((i, c)) = line.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+            return Err(ParseError::new(report, &line[i..i + c.len_utf8()], "a bit"));
+        }
+        match width {
+            None => width = Some(line.len()),
+            Some(width) if width != line.len() => {
+                return Err(ParseError::new(
+                    report,
+                    line,
+                    format!("a binary number of {width} bits"),
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
+type _OxygenGeneratorRating = usize;
+type _CO2ScrubberRating = usize;
+
+fn _calculate_oxygen_generator_rating(report: &str) -> _OxygenGeneratorRating {
+    let binary_numbers = report.lines().collect::<Vec<&str>>();
+
+    let Some(first) = binary_numbers.first() else {
+        return 0;
+    };
+    let bit_depth = first.len();
+
+    for i in 0..bit_depth {
+        let _num_ones = binary_numbers
+            .iter()
+            .filter(|number| number.as_bytes()[i] == b'1')
+            .count();
+    }
+
+    0
+}
+
+type GammaRate = usize;
+type EpsilonRate = usize;
+
+fn calculate_rates(report: &str) -> (GammaRate, EpsilonRate) {
+    let mut position_counts = Vec::new();
+    let mut numbers = 0;
+    for binary_number in report.lines() {
+        for (i, c) in binary_number.chars().enumerate() {
+            if position_counts.len() < i + 1 {
+                position_counts.push(0);
+            }
+            // The report has been validated to only contain bits.
+            if c == '1' {
+                position_counts[i] += 1;
+            }
+        }
+        numbers += 1;
+    }
+    let mut gamma_rate: GammaRate = 0;
+    let mut epsilon_rate: EpsilonRate = 0;
+
+    for (i, count) in position_counts.iter().rev().enumerate() {
+        if *count > numbers / 2 {
+            // most common bit is 1
+            gamma_rate += 1 << i;
+        } else {
+            // least common bit is 1
+            epsilon_rate += 1 << i;
+        }
+    }
+
+    (gamma_rate, epsilon_rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculate_rates, Day3};
+    use crate::{
+        examples::{assert_examples, input},
+        Solution,
+    };
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day3>();
+    }
+
+    #[test]
+    fn test_part1_example() {
+        let (gamma_rate, epsilon_rate) = calculate_rates(&input::<Day3>());
+        let power_consumption = gamma_rate * epsilon_rate;
+        assert_eq!(power_consumption, 198);
+    }
+
+    #[test]
+    fn bad_report() {
+        let e = Day3::parse("00100\n11210").unwrap_err().to_string();
+        assert!(
+            e.contains("line 2, column 3: expected a bit, found `2`"),
+            "{e}"
+        );
+
+        let e = Day3::parse("00100\n1111").unwrap_err().to_string();
+        assert!(
+            e.contains("line 2, column 1: expected a binary number of 5 bits, found `1111`"),
+            "{e}"
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! # Day 4: Giant Squid
+//! A giant squid has attached itself to the submarine and wants to play [Bingo].
+//!
+//! The game (your puzzle input) starts with the order in which the numbers are drawn, followed by the boards, each
+//! a 5x5 grid of numbers. A board wins as soon as all numbers in any of its rows or columns have been drawn. Its
+//! score is the sum of its numbers that have not been drawn, multiplied by the number that was just drawn. For
+//! example:
+//!
+//! ```not_rust example part1:4512 part2:1924
+//! 7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+//!
+//! 22 13 17 11  0
+//! 8  2 23  4 24
+//! 21  9 14 16  7
+//! 6 10  3 18  5
+//! 1 12 20 15 19
+//!
+//! 3 15  0  2 22
+//! 9 18 13 17  5
+//! 19  8  7 25 23
+//! 20 11 10 24  4
+//! 14 21 16 12  6
+//!
+//! 14 21 17 24  4
+//! 10 16 15  9 19
+//! 18  8 23 26 20
+//! 22 11 13  6  5
+//! 2  0 12  3  7
+//! ```
+//! The third board wins first, when 24 is drawn, with 188 numbers left unmarked for a score of 4512.
+//!
+//! What will the score of the first winning board be?
+//!
+//! # Part Two
+//! To let the squid win, figure out which board wins last. In the example above that is the second board, which
+//! wins when 13 is drawn with its unmarked numbers summing to 148, for a score of 1924.
+//!
+//! What will the score of the last winning board be?
+use crate::{grid::Grid, ParseError, Result, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const SOURCE: &'static str = include_str!("mod.rs");
+
+    type Input = Bingo;
+    type Answer = Number;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Bingo::try_from(input)?)
+    }
+
+    fn part1(game: &Self::Input) -> Result<Self::Answer> {
+        Ok(game.play())
+    }
+}
+
+pub struct Bingo {
+    drawn: Vec<Number>,
+    boards: Vec<Board>,
+}
+
+impl TryFrom<&str> for Bingo {
+    type Error = ParseError;
+
+    /// Parse the drawn [Number]s followed by the boards, each preceded by an empty line.
+    fn try_from(input: &str) -> std::result::Result<Self, Self::Error> {
+        let mut parts = input.split("\n\n");
+        let drawn = parts.next().unwrap_or_default();
+        // The number of input lines before the next board.
+        let mut skipped = drawn.matches('\n').count() + 2;
+        let drawn = drawn
+            .split(',')
+            .map(|n| {
+                n.parse()
+                    .map_err(|_| ParseError::new(input, n, "a drawn number"))
+            })
+            .collect::<std::result::Result<_, _>>()?;
+
+        let mut boards = Vec::new();
+        for board in parts {
+            boards.push(Board::try_from(board).map_err(|e| e.shifted(skipped))?);
+            skipped += board.matches('\n').count() + 2;
+        }
+
+        Ok(Self { drawn, boards })
+    }
+}
+
+impl Bingo {
+    fn play(&self) -> Number {
+        for i in 1..self.drawn.len() {
+            crate::event!(Trace, "draw", step = i, number = self.drawn[i - 1]);
+            let winner = self
+                .boards
+                .iter()
+                .enumerate()
+                .find_map(|(board, b)| Some((board, b.score(&self.drawn[0..i])?)));
+            if let Some((board, score)) = winner {
+                crate::event!(
+                    Debug,
+                    "bingo",
+                    step = i,
+                    number = self.drawn[i - 1],
+                    board = board,
+                    score = score,
+                );
+                return score;
+            }
+        }
+        0
+    }
+}
+
+/// A bingo [Board] number
+pub type Number = usize;
+
+/// A bingo [Board] with a 5x5 grid of [Number]s
+#[derive(Debug)]
+struct Board {
+    numbers: Grid<Number>,
+}
+
+impl TryFrom<&str> for Board {
+    type Error = ParseError;
+
+    /// Create a new bingo [Board] from an `input` str slice.
+    ///
+    /// Creation fails if there are non-numeric values in the input or if it is not 5 rows of 5 [Number]s.
+    fn try_from(input: &str) -> std::result::Result<Self, Self::Error> {
+        let numbers = Grid::parse_numbers(input)?;
+
+        if let Some(row) = input.lines().nth(Self::SIZE) {
+            return Err(ParseError::new(input, row, "the end of the board"));
+        }
+        if numbers.rows() > 0 && numbers.cols() != Self::SIZE {
+            let row = input.lines().next().unwrap_or_default();
+            return Err(ParseError::new(
+                input,
+                row,
+                format!("a row of {} numbers", Self::SIZE),
+            ));
+        }
+        if numbers.rows() != Self::SIZE {
+            return Err(ParseError::at(
+                input,
+                input.len(),
+                0,
+                format!("{} rows", Self::SIZE),
+            ));
+        }
+
+        Ok(Self { numbers })
+    }
+}
+
+impl Board {
+    /// The number of rows and columns.
+    const SIZE: usize = 5;
+
+    /// Get the [Number] on the bingo [Board] at a particular `row` and `col`.
+    ///
+    /// Returns `None` if the `row` and `col` combination is invalid.
+    #[cfg(test)]
+    fn get_number(&self, row: usize, col: usize) -> Option<Number> {
+        self.numbers.get(row, col).copied()
+    }
+
+    pub fn score(&self, drawn: &[Number]) -> Option<Number> {
+        let marked = self.find_marked(drawn);
+        if Self::has_complete_row(&marked) || Self::has_complete_col(&marked) {
+            let sum: Number = self
+                .numbers
+                .iter()
+                .filter_map(|(p, n)| if marked[p] { None } else { Some(*n) })
+                .sum();
+            Some(sum * drawn[drawn.len() - 1])
+        } else {
+            None
+        }
+    }
+
+    /// Find all of the marked bingo [Number]s given the `drawn` numbers and return a boolean map corresponding to the marked [Number]s.
+    fn find_marked(&self, drawn: &[Number]) -> Grid<bool> {
+        self.numbers.map(|n| drawn.contains(n))
+    }
+
+    fn has_complete_row(marked: &Grid<bool>) -> bool {
+        (0..marked.rows()).any(|row| marked.row(row).iter().all(|&m| m))
+    }
+
+    fn has_complete_col(marked: &Grid<bool>) -> bool {
+        (0..marked.cols()).any(|col| marked.col(col).all(|&m| m))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bingo, Board, Day4};
+    use crate::examples::{assert_examples, input};
+
+    const BOARD_INPUT: &str = "22 13 17 11  0
+8  2 23  4 24
+21  9 14 16  7
+6 10  3 18  5
+1 12 20 15 19";
+
+    #[test]
+    fn test_board_new() {
+        let board = Board::try_from(BOARD_INPUT).unwrap();
+
+        assert_eq!(board.numbers[(0, 0)], 22);
+        assert_eq!(board.numbers[(4, 4)], 19);
+    }
+
+    #[test]
+    fn test_board_get_number() {
+        let board = Board::try_from(BOARD_INPUT).unwrap();
+
+        assert_eq!(board.get_number(0, 0), Some(22));
+        assert_eq!(board.get_number(4, 4), Some(19));
+        assert_eq!(board.get_number(7, 0), None);
+        assert_eq!(board.get_number(0, 7), None);
+    }
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day4>();
+    }
+
+    #[test]
+    fn test_bingo() {
+        let bingo = Bingo::try_from(input::<Day4>().as_str()).unwrap();
+        assert_eq!(bingo.play(), 4512);
+    }
+
+    #[test]
+    fn bad_bingo() {
+        let e = Bingo::try_from("7,4,x\n\n1 2 3 4 5").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, 5, "x"));
+
+        let example = input::<Day4>().replace("22 11 13", "22 13");
+        let e = Bingo::try_from(example.as_str()).err().unwrap();
+        assert_eq!((e.line, e.column), (18, 1));
+        assert_eq!(e.expected, "a row of 5 numbers");
+
+        let e = Board::try_from("1 2 3 4 5\n6 7 8 9 y").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 9, "y"));
+        let e = Board::try_from(BOARD_INPUT.split_once("\n1 ").unwrap().0)
+            .err()
+            .unwrap();
+        assert_eq!(e.expected, "5 rows");
+    }
+}
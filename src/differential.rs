@@ -0,0 +1,229 @@
+//! Differential testing of the [Implementation](crate::Implementation)s of a [Solution]: every alternative must give
+//! the same answer as the main implementation of its part.
+//!
+//! When they disagree, the input is shrunk to the smallest input that still shows the [Disagreement], first by
+//! dropping whole blocks between empty lines and then single lines, so that the bug can be studied by hand.
+use crate::{registry::solve_part, Answer, Part, Result, Solution};
+use std::fmt;
+
+/// The name of the main implementation of a part, [`Solution::part1`] or [`Solution::part2`].
+pub const MAIN: &str = "main";
+
+/// An alternative implementation that did not give the same answer as the main implementation of its part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub day: u8,
+    pub part: Part,
+    pub name: &'static str,
+    /// The answer of the main implementation, or its error.
+    pub expected: String,
+    /// The answer of the alternative, or its error.
+    pub answer: String,
+    /// The smallest input found that still shows the [Disagreement].
+    pub input: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} part {}: `{}` answered {} where `{MAIN}` answered {} for this input:\n{}",
+            self.day, self.part, self.name, self.answer, self.expected, self.input
+        )
+    }
+}
+
+/// Run every alternative of `S` on `input` and report each one that disagrees with the main implementation, with the
+/// input shrunk as far as possible.
+///
+/// # Errors
+/// Returns an error when the `input` cannot be parsed.
+pub fn check<S: Solution>(input: &str) -> Result<Vec<Disagreement>> {
+    let parsed = S::parse(input)?;
+
+    let mut disagreements = Vec::new();
+    for alternative in S::ALTERNATIVES {
+        if answers::<S>(&parsed, alternative).is_none() {
+            continue;
+        }
+        let input = shrink(input, |candidate| {
+            S::parse(candidate).is_ok_and(|parsed| answers::<S>(&parsed, alternative).is_some())
+        });
+        // The shrunk input is known to parse and disagree.
+        if let Some((expected, answer)) = S::parse(&input)
+            .ok()
+            .and_then(|parsed| answers::<S>(&parsed, alternative))
+        {
+            disagreements.push(Disagreement {
+                day: S::DAY,
+                part: alternative.part,
+                name: alternative.name,
+                expected,
+                answer,
+                input,
+            });
+        }
+    }
+    Ok(disagreements)
+}
+
+/// The answers of the main implementation and the `alternative` when they differ.
+///
+/// Answers differ as [Answer]s do, so art agrees with the letters it spells, while errors only agree with the same
+/// error. Parts without a main implementation have nothing to disagree with.
+fn answers<S: Solution>(
+    input: &S::Input,
+    alternative: &crate::Implementation<S>,
+) -> Option<(String, String)> {
+    let render = |answer: Result<Answer>| match answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {e}"),
+    };
+    let expected = solve_part::<S>(input, alternative.part);
+    if matches!(expected, Err(crate::Error::Unimplemented)) {
+        return None;
+    }
+    let answer = (alternative.solve)(input).map(Into::into);
+    let agree = match (&expected, &answer) {
+        (Ok(expected), Ok(answer)) => expected == answer,
+        (Err(expected), Err(answer)) => expected.to_string() == answer.to_string(),
+        _ => false,
+    };
+    (!agree).then(|| (render(expected), render(answer)))
+}
+
+/// Shrink an `input` that `fails` to a smaller one that still does, first by whole blocks and then by lines.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let input = shrink_by(input, "\n\n", &fails);
+    shrink_by(&input, "\n", &fails)
+}
+
+/// Remove ever smaller runs of the pieces of `input` between `separator`s as long as the rest still `fails`.
+fn shrink_by(input: &str, separator: &str, fails: &impl Fn(&str) -> bool) -> String {
+    let mut pieces: Vec<&str> = input.split(separator).collect();
+    let mut run = (pieces.len() / 2).max(1);
+    while run >= 1 && pieces.len() > 1 {
+        let mut start = 0;
+        while start < pieces.len() && pieces.len() > 1 {
+            let end = (start + run).min(pieces.len());
+            let candidate: Vec<&str> = pieces[..start]
+                .iter()
+                .chain(&pieces[end..])
+                .copied()
+                .collect();
+            if !candidate.is_empty() && fails(&candidate.join(separator)) {
+                pieces = candidate;
+            } else {
+                start += run;
+            }
+        }
+        run /= 2;
+    }
+    pieces.join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, shrink, Disagreement};
+    use crate::{gen, registry, Answer, Implementation, Part, Solution};
+
+    /// A [Solution] with an alternative that forgets about negative numbers.
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<i64>;
+        type Answer = i64;
+
+        fn parse(input: &str) -> crate::Result<Self::Input> {
+            input
+                .lines()
+                .map(|l| l.parse().map_err(|e| crate::Error::Parse(Box::new(e))))
+                .collect()
+        }
+
+        fn part1(numbers: &Self::Input) -> crate::Result<Self::Answer> {
+            Ok(numbers.iter().sum())
+        }
+
+        const ALTERNATIVES: &'static [Implementation<Self>] = &[Implementation {
+            name: "positive",
+            part: Part::One,
+            solve: |numbers| Ok(numbers.iter().filter(|&&n| n > 0).sum()),
+        }];
+    }
+
+    /// A [Solution] whose alternative draws the letters that the main implementation reads.
+    struct Letters;
+
+    impl Solution for Letters {
+        const DAY: u8 = 0;
+
+        type Input = ();
+        type Answer = Answer;
+
+        fn parse(_: &str) -> crate::Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part1((): &Self::Input) -> crate::Result<Self::Answer> {
+            Ok(Answer::from("HI"))
+        }
+
+        const ALTERNATIVES: &'static [Implementation<Self>] = &[Implementation {
+            name: "art",
+            part: Part::One,
+            solve: |()| {
+                Ok(Answer::Art(
+                    "#..#..###\n#..#...#.\n####...#.\n#..#...#.\n#..#...#.\n#..#..###\n"
+                        .to_string(),
+                ))
+            },
+        }];
+    }
+
+    #[test]
+    fn answers_are_compared_as_answers() {
+        assert_eq!(check::<Letters>("x\n").unwrap(), []);
+    }
+
+    #[test]
+    fn disagreements_are_shrunk() {
+        assert_eq!(check::<Sum>("1\n2\n3\n").unwrap(), []);
+        assert_eq!(
+            check::<Sum>("1\n2\n-3\n4\n5\n6\n").unwrap(),
+            [Disagreement {
+                day: 0,
+                part: Part::One,
+                name: "positive",
+                expected: "-3".to_string(),
+                answer: "0".to_string(),
+                input: "-3".to_string(),
+            }]
+        );
+
+        let blocks = "a\nb\n\nc\nd\n\ne\nbad\nf";
+        assert_eq!(shrink(blocks, |input| input.contains("bad")), "bad");
+    }
+
+    /// Every registered alternative agrees with the main implementation on generated and real inputs.
+    #[test]
+    fn registered_alternatives_agree() {
+        for entry in registry::DAYS {
+            if entry.alternatives().is_empty() {
+                continue;
+            }
+            let mut inputs: Vec<String> = crate::read_input(entry.year, entry.day, None)
+                .into_iter()
+                .collect();
+            if let Some(generator) = gen::find(entry.year, entry.day) {
+                inputs.extend((0..10).map(|seed| generator.generate(seed, 20)));
+            }
+            for input in inputs {
+                let disagreements = entry.differential(&input).unwrap();
+                assert!(disagreements.is_empty(), "{}", disagreements[0]);
+            }
+        }
+    }
+}
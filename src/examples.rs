@@ -0,0 +1,175 @@
+//! Examples are taken from the puzzle description in each day's `//!` module docs, so they live in one place.
+//!
+//! A code block whose info string contains `example` is an [Example]; the answers it states are given as `part1:...`
+//! and `part2:...` in the same info string, as rustdoc only accepts `=` in attributes it knows:
+//!
+//! ```not_rust
+//! //! ```not_rust example part1:7 part2:5
+//! //! 199
+//! //! 200
+//! //! ```
+//! ```
+use crate::{Answer, Error, Part, Solution};
+
+/// An [Example] input from the puzzle description along with the answers it states.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    /// The expected answer for `part`, if the puzzle states one.
+    #[must_use]
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Extract the [Example]s from the `//!` docs of a module's `source`.
+#[must_use]
+pub fn extract(source: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    // `None` outside of code blocks, `Some(None)` inside one that is not an example.
+    let mut block: Option<Option<(Example, Vec<&str>)>> = None;
+
+    let docs = source.lines().filter_map(|line| {
+        let doc = line.trim_start().strip_prefix("//!")?;
+        Some(doc.strip_prefix(' ').unwrap_or(doc))
+    });
+
+    for line in docs {
+        if let Some(info) = line.trim_end().strip_prefix("```") {
+            match block.take() {
+                None => block = Some(info_example(info).map(|example| (example, Vec::new()))),
+                Some(Some((mut example, lines))) => {
+                    example.input = lines.join("\n");
+                    examples.push(example);
+                }
+                Some(None) => {}
+            }
+        } else if let Some(Some((_, lines))) = block.as_mut() {
+            lines.push(line);
+        }
+    }
+
+    examples
+}
+
+/// Parse the info string of a code block fence, returning an empty [Example] if it is marked as one.
+fn info_example(info: &str) -> Option<Example> {
+    let tokens = info.split([',', ' ', '\t']).filter(|t| !t.is_empty());
+    if !tokens.clone().any(|t| t == "example") {
+        return None;
+    }
+
+    let mut example = Example {
+        input: String::new(),
+        part1: None,
+        part2: None,
+    };
+    for token in tokens {
+        if let Some(answer) = token.strip_prefix("part1:") {
+            example.part1 = Some(answer.to_string());
+        } else if let Some(answer) = token.strip_prefix("part2:") {
+            example.part2 = Some(answer.to_string());
+        }
+    }
+    Some(example)
+}
+
+/// The [Example]s of the [Solution] `S`, taken from its module docs.
+#[must_use]
+pub fn of<S: Solution>() -> Vec<Example> {
+    extract(S::SOURCE)
+}
+
+/// The input of the first [Example] of the [Solution] `S`.
+///
+/// # Panics
+/// Panics when the module docs of `S` have no example.
+#[must_use]
+pub fn input<S: Solution>() -> String {
+    of::<S>().into_iter().next().map_or_else(
+        || panic!("day {} has no example in its docs", S::DAY),
+        |e| e.input,
+    )
+}
+
+/// Solve every [Example] of the [Solution] `S` and assert that each answer it states is reproduced.
+///
+/// Parts that are not implemented yet are skipped.
+///
+/// # Panics
+/// Panics when an example cannot be parsed or solved, or when an answer differs.
+pub fn assert_examples<S: Solution>() {
+    for (i, example) in of::<S>().iter().enumerate() {
+        let input =
+            S::parse(&example.input).unwrap_or_else(|e| panic!("day {} example {i}: {e}", S::DAY));
+        for part in Part::ALL {
+            let Some(expected) = example.answer(part) else {
+                continue;
+            };
+            let answer = match crate::registry::solve_part::<S>(&input, part) {
+                Ok(answer) => answer,
+                Err(Error::Unimplemented) => continue,
+                Err(e) => panic!("day {} example {i} part {part}: {e}", S::DAY),
+            };
+            assert_eq!(
+                answer,
+                Answer::parse(expected),
+                "day {} example {i} part {part}",
+                S::DAY
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract, Example};
+
+    #[test]
+    fn extract_examples() {
+        let source = r"//! # Day 0
+//! Some prose.
+//!
+//! ```not_rust
+//! not an example
+//! ```
+//! ```not_rust example part1:7
+//! 1
+//!
+//! 2
+//! ```
+//!
+//! ```not_rust,example,part1:x,part2:y
+//! a b
+//! ```
+/// Not a module doc.
+/// ```not_rust example part1:1
+/// ignored
+/// ```
+fn main() {}";
+
+        assert_eq!(
+            extract(source),
+            [
+                Example {
+                    input: "1\n\n2".to_string(),
+                    part1: Some("7".to_string()),
+                    part2: None,
+                },
+                Example {
+                    input: "a b".to_string(),
+                    part1: Some("x".to_string()),
+                    part2: Some("y".to_string()),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! Seeded [Generator]s of random but valid puzzle inputs of any size, for finding the bugs and slow spots that the
+//! small examples and the single real input never reach.
+//!
+//! The same day, seed and size always produce the same input, so a failing input can be reproduced from those three
+//! numbers alone.
+use crate::YEAR;
+use std::fmt::Write;
+
+/// A small, fast and deterministic pseudo-random number generator (`SplitMix64`).
+///
+/// It is nowhere near good enough for cryptography, but plenty for making up puzzle inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, or 0 when `n` is 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        // Taking the high bits of the 128-bit product is both faster and less biased than `%`.
+        let n = n as u64;
+        usize::try_from((u128::from(self.next_u64()) * u128::from(n)) >> 64).unwrap_or(0)
+    }
+
+    /// Whether an event with the given `chance` out of 1 happens.
+    pub fn chance(&mut self, chance: f64) -> bool {
+        #[allow(clippy::cast_precision_loss)]
+        let roll = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        roll < chance
+    }
+
+    /// Shuffle `items` in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A [Generator] of inputs for one day.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// The size of a typical real input.
+    pub default_size: usize,
+    /// What the size counts, such as "depths" or "boards".
+    pub unit: &'static str,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    /// Generate an input of `size` [units](Self::unit) from the `seed`.
+    #[must_use]
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+/// Every day's [Generator], in year and day order.
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        year: YEAR,
+        day: 1,
+        default_size: 2000,
+        unit: "depths",
+        generate: sonar_sweep,
+    },
+    Generator {
+        year: YEAR,
+        day: 2,
+        default_size: 1000,
+        unit: "commands",
+        generate: course,
+    },
+    Generator {
+        year: YEAR,
+        day: 3,
+        default_size: 1000,
+        unit: "numbers",
+        generate: diagnostic_report,
+    },
+    Generator {
+        year: YEAR,
+        day: 4,
+        default_size: 100,
+        unit: "boards",
+        generate: bingo,
+    },
+];
+
+/// Find the [Generator] for `day` of `year`, if it has one.
+#[must_use]
+pub fn find(year: u16, day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.year == year && g.day == day)
+}
+
+/// Depths that mostly keep going down, but wander up now and then like a real sea floor.
+fn sonar_sweep(rng: &mut Rng, size: usize) -> String {
+    let mut depth = 100 + rng.below(100);
+    let mut input = String::new();
+    for _ in 0..size {
+        let _ = writeln!(input, "{depth}");
+        let step = rng.below(30);
+        depth = if rng.chance(0.3) {
+            depth.saturating_sub(step)
+        } else {
+            depth + step
+        };
+    }
+    input
+}
+
+/// A course that never takes the submarine above the surface, in either reading of the commands.
+fn course(rng: &mut Rng, size: usize) -> String {
+    let mut depth = 0;
+    let mut input = String::new();
+    for _ in 0..size {
+        let amount = 1 + rng.below(9);
+        let _ = match rng.below(3) {
+            0 => writeln!(input, "forward {amount}"),
+            1 if depth >= amount => {
+                depth -= amount;
+                writeln!(input, "up {amount}")
+            }
+            _ => {
+                depth += amount;
+                writeln!(input, "down {amount}")
+            }
+        };
+    }
+    input
+}
+
+/// Twelve bit binary numbers, like the real reports.
+fn diagnostic_report(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let number = rng.below(1 << 12);
+        let _ = writeln!(input, "{number:012b}");
+    }
+    input
+}
+
+/// Every number from 0 to 99 drawn in a random order, followed by boards of distinct numbers from that range.
+fn bingo(rng: &mut Rng, size: usize) -> String {
+    let mut drawn: Vec<usize> = (0..100).collect();
+    rng.shuffle(&mut drawn);
+    let mut input = drawn
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+    input.push('\n');
+
+    let mut numbers: Vec<usize> = (0..100).collect();
+    for _ in 0..size {
+        rng.shuffle(&mut numbers);
+        input.push('\n');
+        for row in numbers[..25].chunks(5) {
+            let row: Vec<String> = row.iter().map(|n| format!("{n:>2}")).collect();
+            input.push_str(&row.join(" "));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, Rng, GENERATORS};
+    use crate::{registry, Error, Part, YEAR};
+
+    #[test]
+    fn generators_are_deterministic() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert!((0..1000).all(|_| a.below(10) < 10));
+
+        for generator in GENERATORS {
+            let input = generator.generate(42, 10);
+            assert_eq!(input, generator.generate(42, 10), "day {}", generator.day);
+            assert_ne!(input, generator.generate(43, 10), "day {}", generator.day);
+        }
+        assert!(find(YEAR, 25).is_none());
+        assert!(find(2015, 1).is_none());
+    }
+
+    /// Solve large generated inputs of every day that has a [Generator](super::Generator) and make sure every
+    /// implemented part comes up with an answer.
+    #[test]
+    fn stress() {
+        for generator in GENERATORS {
+            for seed in 0..3 {
+                let input = generator.generate(seed, generator.default_size * 2);
+                // Days that are not compiled in cannot be solved.
+                let Ok(entry) = registry::find(generator.year, generator.day) else {
+                    continue;
+                };
+                let run = entry
+                    .solve(&input, &Part::ALL)
+                    .unwrap_or_else(|e| panic!("day {} seed {seed}: {e}", generator.day));
+                for part in run.parts {
+                    assert!(
+                        matches!(part.answer, Ok(_) | Err(Error::Unimplemented)),
+                        "day {} part {} seed {seed}: {:?}",
+                        generator.day,
+                        part.part,
+                        part.answer
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,44 +1,88 @@
-use std::{
-    fs::{create_dir, File},
-    io::{self, Write},
-    path::PathBuf,
-};
-
-fn main() -> io::Result<()> {
-    const BOILER_PLATE: &str = r#"#![deny(clippy::all, clippy::pedantic)]
-
-use std::{
-    fs::File,
-    io::{self, Read},
-};
-
-fn main() -> io::Result<()> {
-    let mut f = File::open("src/day1/input.txt")?;
-    let mut buf = String::new();
-    f.read_to_string(&mut buf)?;
-
-    Ok(())
-}
+//! # generate
+//! Scaffolds the days that do not have a solution yet from the templates in `templates/`.
+//!
+//! ```not_rust
+//! generate                   # every missing day
+//! generate --day 6           # only day 6
+//! generate --day 6 --force   # overwrite day 6 with a fresh template
+//! generate --year 2022 -d 1  # day 1 of 2022, in src/y2022/day1
+//! ```
+//!
+//! The year defaults to the `year` of [`aoc.toml`](aoc::config). The days of a year other than [`aoc::YEAR`] go in a
+//! module of their own with its own answer store, as described by [`Scaffold`].
+#![deny(clippy::all, clippy::pedantic)]
+
+use aoc::scaffold::{Outcome, Scaffold};
+use std::{env, process::ExitCode};
+
+const USAGE: &str = "usage: generate [--day <DAY>]... [--year <YEAR>] [--force]";
+
+fn main() -> ExitCode {
+    if let Err(e) = aoc::config::init() {
+        eprintln!("error: {e}");
+        return ExitCode::from(2);
+    }
+    let (days, year, force) = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {e}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let scaffold = Scaffold::new(env!("CARGO_MANIFEST_DIR"), year, force);
+    let explicit = !days.is_empty();
+    let days = if explicit { days } else { (1..=25).collect() };
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn it_works() {
-        assert_eq!(1, 1)
+    let mut failed = false;
+    for day in days {
+        // Without --day, existing days are silently left alone.
+        let outcome = if explicit {
+            scaffold.generate(day)
+        } else {
+            scaffold.generate_missing(day)
+        };
+        match outcome {
+            Ok(Outcome::Created) => println!("day {day}: created"),
+            Ok(Outcome::Overwritten) => println!("day {day}: overwritten"),
+            Ok(Outcome::Skipped) => {}
+            Err(e) => {
+                failed = true;
+                eprintln!("day {day}: {e}");
+            }
+        }
     }
-}"#;
 
-    for day in 1..=25 {
-        let path = format!("src/day{}", day);
-        create_dir(&path)?;
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
 
-        let main: PathBuf = [&path, "main.rs"].iter().collect();
-        let mut f = File::create(main)?;
-        write!(&mut f, "{}", BOILER_PLATE)?;
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(Vec<u8>, u16, bool), String> {
+    let mut days = Vec::new();
+    let mut year = aoc::config::get().year;
+    let mut force = false;
 
-        let input: PathBuf = [&path, "input.txt"].iter().collect();
-        File::create(input)?;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => {
+                let day = args.next().ok_or("--day needs a value")?;
+                days.push(day.parse().map_err(|_| format!("invalid day: {day}"))?);
+            }
+            "--year" | "-y" => {
+                let value = args.next().ok_or("--year needs a value")?;
+                year = value
+                    .parse()
+                    .ok()
+                    .filter(|&year| year >= aoc::FIRST_YEAR)
+                    .ok_or_else(|| format!("invalid year: {value}"))?;
+            }
+            "--force" | "-f" => force = true,
+            arg => return Err(format!("unexpected argument: {arg}")),
+        }
     }
 
-    Ok(())
+    Ok((days, year, force))
 }
@@ -0,0 +1,346 @@
+//! A rectangular [Grid] of cells, as found in many puzzle inputs: bingo boards, heightmaps, octopus grids, risk maps
+//! and images.
+//!
+//! Cells are addressed by `(row, col)` positions starting at the top left corner.
+use crate::ParseError;
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// A `(row, col)` position in a [Grid].
+pub type Position = (usize, usize);
+
+/// The offsets of the 4 orthogonal neighbors of a cell: up, left, right and down.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The offsets of all 8 neighbors of a cell, row by row.
+const ALL: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular [Grid] of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A [Grid] of `rows` by `cols` cells, taken row by row from `cells`.
+    ///
+    /// Returns `None` when there are not exactly `rows * cols` cells.
+    #[must_use]
+    pub fn from_cells(rows: usize, cols: usize, cells: Vec<T>) -> Option<Self> {
+        (rows.checked_mul(cols) == Some(cells.len())).then_some(Self { rows, cols, cells })
+    }
+
+    /// Parse a [Grid] with a cell per character, such as `#.#` or `2199943210`, converting each character with `cell`.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] expecting `expected` at a character that `cell` rejects, or at a row that is not as
+    /// wide as the first.
+    pub fn parse_chars(
+        input: &str,
+        cell: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        Self::parse_rows(input, "characters", |line| {
+            line.char_indices()
+                .map(|(i, c)| {
+                    cell(c)
+                        .ok_or_else(|| ParseError::new(input, &line[i..i + c.len_utf8()], expected))
+                })
+                .collect()
+        })
+    }
+
+    /// Parse the rows of a [Grid] from the lines of `input` with `row`, making sure they are all as wide as the first.
+    fn parse_rows(
+        input: &str,
+        cells: &str,
+        row: impl Fn(&str) -> Result<Vec<T>, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut rows = 0;
+        let mut cols = None;
+        let mut all = Vec::new();
+        for line in input.lines() {
+            let line = line.trim_end_matches('\r');
+            let row = row(line)?;
+            let width = *cols.get_or_insert(row.len());
+            if row.len() != width {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("a row of {width} {cells}"),
+                ));
+            }
+            all.extend(row);
+            rows += 1;
+        }
+
+        Ok(Self {
+            rows,
+            cols: cols.unwrap_or(0),
+            cells: all,
+        })
+    }
+
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The cell at `row` and `col`, or `None` outside of the [Grid].
+    #[must_use]
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.index_of(row, col).map(|i| &self.cells[i])
+    }
+
+    /// The cell at `row` and `col`, or `None` outside of the [Grid].
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.index_of(row, col).map(|i| &mut self.cells[i])
+    }
+
+    fn index_of(&self, row: usize, col: usize) -> Option<usize> {
+        (row < self.rows && col < self.cols).then_some(row * self.cols + col)
+    }
+
+    /// Every [Position] with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        let cols = self.cols.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / cols, i % cols), cell))
+    }
+
+    /// The positions of the up to 4 orthogonal neighbors of `position` within the [Grid].
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbors(position, &ORTHOGONAL)
+    }
+
+    /// The positions of the up to 8 orthogonal and diagonal neighbors of `position` within the [Grid].
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbors(position, &ALL)
+    }
+
+    fn neighbors(
+        &self,
+        (row, col): Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + '_ {
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let row = row.checked_add_signed(dr)?;
+            let col = col.checked_add_signed(dc)?;
+            self.index_of(row, col).map(|_| (row, col))
+        })
+    }
+
+    /// The cells of a `row`.
+    ///
+    /// # Panics
+    /// Panics when the `row` is outside of the [Grid].
+    #[must_use]
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row {row} is outside of the grid");
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// The cells of a `col`, from top to bottom.
+    ///
+    /// # Panics
+    /// Panics when the `col` is outside of the [Grid].
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {col} is outside of the grid");
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    /// The cells on the diagonal from the top left corner towards the bottom right.
+    pub fn diagonal(&self) -> impl Iterator<Item = &T> {
+        (0..self.rows.min(self.cols)).map(|i| &self[(i, i)])
+    }
+
+    /// The cells on the diagonal from the top right corner towards the bottom left.
+    pub fn anti_diagonal(&self) -> impl Iterator<Item = &T> {
+        (0..self.rows.min(self.cols)).map(|i| &self[(i, self.cols - 1 - i)])
+    }
+
+    /// A [Grid] of the same shape with `f` applied to every cell.
+    #[must_use]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A [Grid] of `rows` by `cols` copies of `cell`.
+    #[must_use]
+    pub fn filled(rows: usize, cols: usize, cell: T) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![cell; rows * cols],
+        }
+    }
+
+    /// The [Grid] mirrored along its diagonal, so that its rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.cols)
+            .flat_map(|col| self.col(col).cloned())
+            .collect();
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            cells,
+        }
+    }
+}
+
+impl<T: FromStr> Grid<T> {
+    /// Parse a [Grid] of whitespace-separated numbers, such as a bingo board.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] at a value that is not a number, or at a row that is not as wide as the first.
+    pub fn parse_numbers(input: &str) -> Result<Self, ParseError> {
+        Self::parse_rows(input, "numbers", |line| {
+            line.split_whitespace()
+                .map(|n| n.parse().map_err(|_| ParseError::new(input, n, "a number")))
+                .collect()
+        })
+    }
+}
+
+impl Grid<u8> {
+    /// Parse a [Grid] of single digits without separators, such as a heightmap.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] at a character that is not a digit, or at a row that is not as wide as the first.
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        Self::parse_chars(
+            input,
+            |c| c.to_digit(10).and_then(|d| u8::try_from(d).ok()),
+            "a digit",
+        )
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Position) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Position) -> &mut T {
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is outside of the grid"))
+    }
+}
+
+/// Render the [Grid] a row per line. Without a width, such as `{}`, the cells are written next to each other like a
+/// character grid. With a width, such as `{:2}`, every cell is padded to it and separated by a space like a bingo
+/// board.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for (col, cell) in self.row(row).iter().enumerate() {
+                if col > 0 && f.width().is_some() {
+                    write!(f, " ")?;
+                }
+                cell.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    #[test]
+    fn parse_and_render() {
+        let grid = Grid::parse_chars("#.#\n..#", |c| Some(c == '#'), "a cell").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(
+            grid.map(|&lit| if lit { '#' } else { '.' }).to_string(),
+            "#.#\n..#"
+        );
+
+        let digits = Grid::parse_digits("219\n398").unwrap();
+        assert_eq!(digits.get(1, 2), Some(&8));
+        assert_eq!(digits.get(2, 0), None);
+        assert_eq!(digits.get(0, 3), None);
+
+        let numbers: Grid<u32> = Grid::parse_numbers("22 13\n 8  2").unwrap();
+        assert_eq!(format!("{numbers:>2}"), "22 13\n 8  2");
+        assert_eq!(numbers[(1, 0)], 8);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Grid::parse_digits("123\n4x6").unwrap_err();
+        assert_eq!((e.line, e.column, e.expected.as_str()), (2, 2, "a digit"));
+
+        let e = Grid::<u8>::parse_numbers("1 2 3\n4 5").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 1, "4 5"));
+        assert_eq!(e.expected, "a row of 3 numbers");
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::filled(3, 4, 0);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbors8((2, 3)).collect::<Vec<_>>(),
+            [(1, 2), (1, 3), (2, 2)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn views_and_transpose() {
+        let grid = Grid::from_cells(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(grid.diagonal().copied().collect::<Vec<_>>(), [1, 5]);
+        assert_eq!(grid.anti_diagonal().copied().collect::<Vec<_>>(), [3, 5]);
+        assert_eq!(grid.iter().nth(4).map(|(p, &c)| (p, c)), Some(((1, 1), 5)));
+
+        let transposed = grid.transpose();
+        assert_eq!((transposed.rows(), transposed.cols()), (3, 2));
+        assert_eq!(transposed.to_string(), "14\n25\n36");
+        assert_eq!(transposed.transpose(), grid);
+        assert!(Grid::from_cells(2, 2, vec![1]).is_none());
+    }
+}
@@ -0,0 +1,362 @@
+//! Just enough of an HTTP client to talk to the Advent of Code website without pulling in a TLS stack: `http://` URLs
+//! are requested over a plain [`TcpStream`], and `https://` URLs are handed to the `curl` command.
+use std::{
+    fmt::Write as _,
+    io::{self, Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+use thiserror::Error;
+
+/// How long connecting, sending and receiving may each take.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Error, Debug)]
+pub enum HttpError {
+    #[error("unsupported URL: {0}")]
+    InvalidUrl(String),
+    #[error("request to {url} failed")]
+    Io {
+        url: String,
+        #[source]
+        source: io::Error,
+    },
+    #[error("invalid response from {url}")]
+    InvalidResponse { url: String },
+    #[error("requesting {url} with curl failed: {message}")]
+    Curl { url: String, message: String },
+}
+
+/// The status code and body of an HTTP [Response].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// A `GET` [Request], or a `POST` when it has a form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// The `application/x-www-form-urlencoded` body.
+    pub form: Option<String>,
+}
+
+impl Request {
+    #[must_use]
+    pub fn get(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            headers: Vec::new(),
+            form: None,
+        }
+    }
+
+    /// A `POST` of the form `fields`.
+    #[must_use]
+    pub fn post(url: &str, fields: &[(&str, &str)]) -> Self {
+        let form = fields
+            .iter()
+            .map(|(name, value)| format!("{}={}", encode(name), encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+        Self {
+            form: Some(form),
+            ..Self::get(url)
+        }
+    }
+
+    #[must_use]
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Send the [Request] and wait for the whole [Response].
+    ///
+    /// # Errors
+    /// Returns an [`HttpError`] when the URL is not `http://` or `https://`, the server cannot be reached, or the
+    /// response is not HTTP.
+    pub fn send(&self) -> Result<Response, HttpError> {
+        if let Some(rest) = self.url.strip_prefix("http://") {
+            self.send_plain(rest)
+        } else if self.url.starts_with("https://") {
+            self.send_curl()
+        } else {
+            Err(HttpError::InvalidUrl(self.url.clone()))
+        }
+    }
+
+    fn method(&self) -> &'static str {
+        if self.form.is_some() {
+            "POST"
+        } else {
+            "GET"
+        }
+    }
+
+    /// Send the [Request] over a [`TcpStream`] to the `host[:port]/path` in `rest`.
+    fn send_plain(&self, rest: &str) -> Result<Response, HttpError> {
+        let (host, path) = rest.find('/').map_or((rest, "/"), |i| rest.split_at(i));
+        if host.is_empty() {
+            return Err(HttpError::InvalidUrl(self.url.clone()));
+        }
+        let address = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{host}:80")
+        };
+        let io = |source| HttpError::Io {
+            url: self.url.clone(),
+            source,
+        };
+
+        // HTTP/1.0 makes the server close the connection after a response without chunked encoding.
+        let mut request = format!("{} {path} HTTP/1.0\r\nHost: {host}\r\n", self.method());
+        for (name, value) in &self.headers {
+            let _ = write!(request, "{name}: {value}\r\n");
+        }
+        if let Some(form) = &self.form {
+            let _ = write!(
+                request,
+                "Content-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n",
+                form.len()
+            );
+        }
+        request.push_str("\r\n");
+        request.push_str(self.form.as_deref().unwrap_or_default());
+
+        let mut stream = TcpStream::connect(&address).map_err(io)?;
+        stream.set_read_timeout(Some(TIMEOUT)).map_err(io)?;
+        stream.set_write_timeout(Some(TIMEOUT)).map_err(io)?;
+        stream.write_all(request.as_bytes()).map_err(io)?;
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).map_err(io)?;
+
+        let response = String::from_utf8_lossy(&response);
+        let invalid = || HttpError::InvalidResponse {
+            url: self.url.clone(),
+        };
+        let (head, body) = response.split_once("\r\n\r\n").ok_or_else(invalid)?;
+        let status = head
+            .lines()
+            .next()
+            .filter(|line| line.starts_with("HTTP/"))
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(invalid)?;
+
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+
+    /// Send the [Request] with `curl`, passing everything on its standard input so the headers, which carry the
+    /// session token, do not show up in the process list.
+    fn send_curl(&self) -> Result<Response, HttpError> {
+        let curl = |message: String| HttpError::Curl {
+            url: self.url.clone(),
+            message,
+        };
+
+        let mut config = format!("url = {}\n", quote(&self.url));
+        for (name, value) in &self.headers {
+            let _ = writeln!(config, "header = {}", quote(&format!("{name}: {value}")));
+        }
+        if let Some(form) = &self.form {
+            let _ = writeln!(config, "data = {}", quote(form));
+        }
+        config.push_str("write-out = \"\\n%{http_code}\"\n");
+
+        let mut child = Command::new("curl")
+            .args([
+                "--silent",
+                "--show-error",
+                "--max-time",
+                "30",
+                "--config",
+                "-",
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| curl(format!("curl is needed for https URLs: {e}")))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(config.as_bytes())
+                .map_err(|e| curl(e.to_string()))?;
+        }
+        let output = child.wait_with_output().map_err(|e| curl(e.to_string()))?;
+        if !output.status.success() {
+            return Err(curl(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) =
+            stdout
+                .rsplit_once('\n')
+                .ok_or_else(|| HttpError::InvalidResponse {
+                    url: self.url.clone(),
+                })?;
+        let status = status
+            .trim()
+            .parse()
+            .map_err(|_| HttpError::InvalidResponse {
+                url: self.url.clone(),
+            })?;
+
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// Percent-encode a form `value`.
+fn encode(value: &str) -> String {
+    let mut encoded = String::new();
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(char::from(b));
+            }
+            b' ' => encoded.push('+'),
+            b => {
+                let _ = write!(encoded, "%{b:02X}");
+            }
+        }
+    }
+    encoded
+}
+
+/// Quote a value for a curl config file.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+pub(crate) mod stub {
+    //! An in-process HTTP server answering requests with canned responses, for testing clients.
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// A [Stub] server on a local port that records the requests it receives.
+    pub struct Stub {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Stub {
+        /// Serve `respond(request)` as the `(status, body)` of the response to every request.
+        pub fn serve(respond: impl Fn(&str) -> (u16, String) + Send + 'static) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let recorded = Arc::clone(&requests);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let Ok(mut stream) = stream else { break };
+                    let request = read_request(&mut stream);
+                    let (status, body) = respond(&request);
+                    recorded.lock().unwrap().push(request);
+                    let _ = write!(
+                        stream,
+                        "HTTP/1.0 {status} Stub\r\nContent-Length: {}\r\n\r\n{body}",
+                        body.len()
+                    );
+                }
+            });
+
+            Self { url, requests }
+        }
+
+        /// The requests received so far.
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    /// Read the head of a request and as much of the body as its `Content-Length` says.
+    fn read_request(stream: &mut impl Read) -> String {
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        loop {
+            let n = stream.read(&mut buf).unwrap_or(0);
+            request.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&request);
+            if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                let len = head
+                    .lines()
+                    .find_map(|l| l.strip_prefix("Content-Length: "))
+                    .map_or(0, |l| l.parse().unwrap_or(0));
+                if body.len() >= len {
+                    return text.into_owned();
+                }
+            }
+            if n == 0 {
+                return text.into_owned();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode, stub::Stub, HttpError, Request, Response};
+
+    #[test]
+    fn get_and_post() {
+        let stub = Stub::serve(|request| (200, format!("{} bytes", request.len())));
+
+        let response = Request::get(&format!("{}/2021/day/1/input", stub.url))
+            .header("Cookie", "session=abc")
+            .send()
+            .unwrap();
+        assert_eq!(response.status, 200);
+
+        Request::post(
+            &format!("{}/2021/day/1/answer", stub.url),
+            &[("level", "1"), ("answer", "a b&c")],
+        )
+        .send()
+        .unwrap();
+
+        let requests = stub.requests();
+        assert!(requests[0].starts_with("GET /2021/day/1/input HTTP/1.0\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+        assert!(requests[1].starts_with("POST /2021/day/1/answer HTTP/1.0\r\n"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=a+b%26c"));
+    }
+
+    #[test]
+    fn status_is_returned() {
+        let stub = Stub::serve(|_| (404, "Not Found".to_string()));
+        assert_eq!(
+            Request::get(&stub.url).send().unwrap(),
+            Response {
+                status: 404,
+                body: "Not Found".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn unsupported_url() {
+        assert!(matches!(
+            Request::get("ftp://example.com").send(),
+            Err(HttpError::InvalidUrl(_))
+        ));
+        assert_eq!(encode("ü"), "%C3%BC");
+    }
+}
@@ -0,0 +1,189 @@
+use crate::config::{self, ConfigError};
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// The environment variable naming a directory of `day<N>/input.txt` files, which overrides `input_dir` in `aoc.toml`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("input for day {day} not found: expected {}", .path.display())]
+    Missing { day: u8, path: PathBuf },
+    #[error("input for day {day} is empty: {source_name}")]
+    Empty { day: u8, source_name: Source },
+    #[error("reading {source_name} failed")]
+    Read {
+        source_name: Source,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A file on disk.
+    Path(PathBuf),
+    /// Standard input, selected with `-`.
+    Stdin,
+}
+
+impl Source {
+    /// Resolve the [Source] for `day` of `year`.
+    ///
+    /// In order of precedence, this is
+    /// 1. the explicit `arg`, where `-` means standard input,
+    /// 2. the [input file](day_path) of the day in the [input directory](input_dir).
+    ///
+    /// # Errors
+    /// Returns a [`ConfigError`] when the [`Config`](config::Config) holding the input directory is invalid.
+    pub fn resolve(year: u16, day: u8, arg: Option<&str>) -> Result<Self, ConfigError> {
+        Ok(Self::resolve_with(year, day, arg, &input_dir()?))
+    }
+
+    fn resolve_with(year: u16, day: u8, arg: Option<&str>, input_dir: &Path) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+            None => Source::Path(day_path(input_dir, year, day)),
+        }
+    }
+
+    /// Read the whole puzzle input of `day` from this [Source].
+    ///
+    /// # Errors
+    /// Returns ``Missing`` when the file does not exist, ``Empty`` when there is no input, and ``Read`` for any other I/O failure.
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let read = match self {
+            Source::Path(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut buf = String::new();
+                io::stdin().read_to_string(&mut buf).map(|_| buf)
+            }
+        };
+
+        let input = match (read, self) {
+            (Ok(input), _) => input,
+            (Err(e), Source::Path(path)) if e.kind() == io::ErrorKind::NotFound => {
+                return Err(InputError::Missing {
+                    day,
+                    path: path.clone(),
+                })
+            }
+            (Err(source), _) => {
+                return Err(InputError::Read {
+                    source_name: self.clone(),
+                    source,
+                })
+            }
+        };
+
+        if input.trim().is_empty() {
+            return Err(InputError::Empty {
+                day,
+                source_name: self.clone(),
+            });
+        }
+
+        Ok(input)
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// The directory of `day<N>/input.txt` files from the [`Config`](crate::config::Config): `$AOC_INPUT_DIR` when it is
+/// set, `input_dir` in `aoc.toml`, and `src/` otherwise.
+///
+/// # Errors
+/// Returns the errors of loading the [`Config`](config::Config) when it was not loaded yet.
+pub fn input_dir() -> Result<PathBuf, ConfigError> {
+    Ok(config::init()?.input_dir.clone())
+}
+
+/// The directory holding the committed `day<N>/input.txt` files.
+pub(crate) fn default_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+/// The input file of `day` of `year` in `dir`, which is `input.txt` in the [day's directory](crate::day_dir).
+#[must_use]
+pub fn day_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(crate::day_dir(year, day)).join("input.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_dir, InputError, Source};
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+    };
+
+    #[test]
+    fn explicit_path_wins() {
+        let source = Source::resolve_with(2021, 3, Some("other.txt"), Path::new("inputs"));
+        assert_eq!(source, Source::Path(PathBuf::from("other.txt")));
+    }
+
+    #[test]
+    fn dash_is_stdin() {
+        assert_eq!(
+            Source::resolve_with(2021, 3, Some("-"), &default_dir()),
+            Source::Stdin
+        );
+    }
+
+    #[test]
+    fn input_dir_from_environment() {
+        let source = Source::resolve_with(2021, 3, None, Path::new("inputs"));
+        assert_eq!(source, Source::Path(PathBuf::from("inputs/day3/input.txt")));
+        let source = Source::resolve_with(2022, 3, None, Path::new("inputs"));
+        assert_eq!(
+            source,
+            Source::Path(PathBuf::from("inputs/y2022/day3/input.txt"))
+        );
+    }
+
+    #[test]
+    fn default_is_relative_to_crate_root() {
+        let source = Source::resolve_with(2021, 1, None, &default_dir());
+        assert_eq!(
+            source,
+            Source::Path(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/day1/input.txt"))
+        );
+        assert!(source.read(1).is_ok());
+    }
+
+    #[test]
+    fn missing_input_names_the_file() {
+        let source = Source::resolve_with(2021, 7, None, Path::new("does/not/exist"));
+        let e = source.read(7).unwrap_err();
+        assert!(matches!(e, InputError::Missing { day: 7, .. }));
+        assert_eq!(
+            e.to_string(),
+            "input for day 7 not found: expected does/not/exist/day7/input.txt"
+        );
+    }
+
+    #[test]
+    fn empty_input() {
+        let path = env::temp_dir().join(format!("aoc-empty-input-{}.txt", std::process::id()));
+        fs::write(&path, "\n").unwrap();
+
+        let e = Source::Path(path.clone()).read(5).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(e, InputError::Empty { day: 5, .. }));
+    }
+}
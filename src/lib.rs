@@ -0,0 +1,123 @@
+//! # Advent of Code 2021
+//! Solutions for [Advent of Code 2021](https://adventofcode.com/2021) as a library.
+//!
+//! Each day lives in its own module and implements the [Solution] trait, so the puzzles can be parsed and solved by any tooling that links against this crate.
+#![deny(clippy::all, clippy::pedantic)]
+
+pub mod alloc;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod config;
+#[cfg(feature = "day1")]
+pub mod day1;
+#[cfg(feature = "day10")]
+pub mod day10;
+#[cfg(feature = "day11")]
+pub mod day11;
+#[cfg(feature = "day12")]
+pub mod day12;
+#[cfg(feature = "day13")]
+pub mod day13;
+#[cfg(feature = "day14")]
+pub mod day14;
+#[cfg(feature = "day15")]
+pub mod day15;
+#[cfg(feature = "day16")]
+pub mod day16;
+#[cfg(feature = "day17")]
+pub mod day17;
+#[cfg(feature = "day18")]
+pub mod day18;
+#[cfg(feature = "day19")]
+pub mod day19;
+#[cfg(feature = "day2")]
+pub mod day2;
+#[cfg(feature = "day20")]
+pub mod day20;
+#[cfg(feature = "day21")]
+pub mod day21;
+#[cfg(feature = "day22")]
+pub mod day22;
+#[cfg(feature = "day23")]
+pub mod day23;
+#[cfg(feature = "day24")]
+pub mod day24;
+#[cfg(feature = "day25")]
+pub mod day25;
+#[cfg(feature = "day3")]
+pub mod day3;
+#[cfg(feature = "day4")]
+pub mod day4;
+#[cfg(feature = "day5")]
+pub mod day5;
+#[cfg(feature = "day6")]
+pub mod day6;
+#[cfg(feature = "day7")]
+pub mod day7;
+#[cfg(feature = "day8")]
+pub mod day8;
+#[cfg(feature = "day9")]
+pub mod day9;
+pub mod differential;
+pub mod examples;
+pub mod gen;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod json;
+pub mod ocr;
+pub mod parse;
+pub mod pool;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod search;
+mod solution;
+pub mod status;
+pub mod submit;
+pub mod toml;
+pub mod trace;
+
+pub use answer::Answer;
+pub use examples::Example;
+pub use parse::ParseError;
+pub use registry::Part;
+pub use solution::{read_input, run, Error, Implementation, Result, Solution};
+
+use std::path::{Path, PathBuf};
+
+/// The year of the puzzles this crate started with.
+///
+/// Its days and files keep the names they had before other years were added, such as `src/day4` and `answers.toml`,
+/// while those of other years are named after their year by [`day_dir`] and [`year_file`].
+pub const YEAR: u16 = 2021;
+
+/// The year of the first Advent of Code.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// The directory of `day` of `year`, relative to the sources or the input directory: `day<N>` for [YEAR] and
+/// `y<year>/day<N>` for other years.
+#[must_use]
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    let dir = PathBuf::from(format!("day{day}"));
+    if year == YEAR {
+        dir
+    } else {
+        Path::new(&format!("y{year}")).join(dir)
+    }
+}
+
+/// The name of the per-year `file` of `year`: `file` itself for [YEAR], and with `-<year>` before its extension for
+/// other years, such as `aoc-guesses-2022.toml`.
+#[must_use]
+pub fn year_file(file: &str, year: u16) -> String {
+    if year == YEAR {
+        return file.to_string();
+    }
+    match file.rsplit_once('.') {
+        Some((name, extension)) => format!("{name}-{year}.{extension}"),
+        None => format!("{file}-{year}"),
+    }
+}
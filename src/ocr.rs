@@ -0,0 +1,325 @@
+//! Reading the capital letters that some puzzles draw as `#`/`.` dot art, such as the code from folding the
+//! transparent paper, so that those parts can answer with plain text.
+//!
+//! Two fonts are known: letters 4 dots wide and 6 high with a blank column between them, and letters 6 dots wide and
+//! 10 high with two blank columns between them. The font is picked by the height of the art.
+//!
+//! ```
+//! let art = [
+//!     ".##..###.",
+//!     "#..#.#..#",
+//!     "#..#.###.",
+//!     "####.#..#",
+//!     "#..#.#..#",
+//!     "#..#.###.",
+//! ];
+//! assert_eq!(aoc::ocr::read_str(&art.join("\n")).unwrap(), "AB");
+//! ```
+use crate::{grid::Grid, ParseError};
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    #[error("letters are 6 or 10 rows high, got {0} rows")]
+    Height(usize),
+    #[error("{}", unrecognized(.0))]
+    Unrecognized(Vec<Glyph>),
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
+
+/// A [Glyph] cut out of the art that is not a letter of its font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glyph {
+    /// The position of the glyph among the letters, starting at 0.
+    pub index: usize,
+    /// The dots of the glyph as `#`/`.` rows.
+    pub art: String,
+}
+
+impl fmt::Display for Glyph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "glyph {}:\n{}", self.index, self.art)
+    }
+}
+
+fn unrecognized(glyphs: &[Glyph]) -> String {
+    let glyphs: Vec<String> = glyphs.iter().map(ToString::to_string).collect();
+    format!("unrecognized {}", glyphs.join("\n"))
+}
+
+/// A fixed-width [Font] of capital letters.
+struct Font {
+    width: usize,
+    height: usize,
+    /// The blank columns between two letters.
+    gap: usize,
+    letters: &'static [(char, &'static [&'static str])],
+}
+
+/// The letters 4 dots wide and 6 high.
+const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    gap: 1,
+    letters: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+/// The letters 6 dots wide and 10 high.
+const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    gap: 2,
+    letters: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+/// Read the letters drawn by the lit cells of `art`, which must start at its left edge.
+///
+/// Blank columns on the right are ignored, so the art may be wider than its letters.
+///
+/// # Errors
+/// Returns ``Height`` when the art is neither 6 nor 10 rows high, and ``Unrecognized`` with every [Glyph] that is not
+/// a letter of the font.
+pub fn read(art: &Grid<bool>) -> Result<String, OcrError> {
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|f| f.height == art.rows())
+        .ok_or(OcrError::Height(art.rows()))?;
+
+    let width = (0..art.cols())
+        .rev()
+        .find(|&col| art.col(col).any(|&lit| lit))
+        .map_or(0, |col| col + 1);
+    let pitch = font.width + font.gap;
+
+    let mut text = String::new();
+    let mut unknown = Vec::new();
+    for index in 0..width.div_ceil(pitch) {
+        let glyph = glyph(art, index * pitch, font.width);
+        let letter = font
+            .letters
+            .iter()
+            .find(|(_, rows)| rows.join("\n") == glyph);
+        match letter {
+            Some(&(letter, _)) => text.push(letter),
+            None => unknown.push(Glyph { index, art: glyph }),
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::Unrecognized(unknown))
+    }
+}
+
+/// Read the letters of `#`/`.` art, as in [read].
+///
+/// # Errors
+/// Returns ``Parse`` when the art has other characters or ragged rows, and otherwise the errors of [read].
+pub fn read_str(art: &str) -> Result<String, OcrError> {
+    let art = Grid::parse_chars(
+        art.trim(),
+        |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+        "`#` or `.`",
+    )?;
+    read(&art)
+}
+
+/// The `width` columns of `art` from `start` as `#`/`.` rows, with columns beyond its right edge left blank.
+fn glyph(art: &Grid<bool>, start: usize, width: usize) -> String {
+    let rows: Vec<String> = (0..art.rows())
+        .map(|row| {
+            (start..start + width)
+                .map(|col| match art.get(row, col) {
+                    Some(true) => '#',
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, read_str, Font, Glyph, OcrError, LARGE, SMALL};
+    use crate::grid::Grid;
+
+    /// Draw `text` in `font` the way the puzzles do.
+    fn draw(text: &str, font: &Font) -> String {
+        let gap = ".".repeat(font.gap);
+        let rows: Vec<String> = (0..font.height)
+            .map(|row| {
+                let glyphs: Vec<&str> = text
+                    .chars()
+                    .map(|c| font.letters.iter().find(|(l, _)| *l == c).unwrap().1[row])
+                    .collect();
+                glyphs.join(&gap)
+            })
+            .collect();
+        rows.join("\n")
+    }
+
+    #[test]
+    fn every_letter_is_read() {
+        for font in [SMALL, LARGE] {
+            let text: String = font.letters.iter().map(|&(c, _)| c).collect();
+            assert_eq!(read_str(&draw(&text, &font)).unwrap(), text);
+            for &(_, rows) in font.letters {
+                assert!(rows.len() == font.height && rows.iter().all(|r| r.len() == font.width));
+            }
+        }
+
+        // Trailing blank columns, as left by a grid that is wider than the code.
+        let art = draw("HI", &SMALL).replace('\n', "....\n") + "....";
+        assert_eq!(read_str(&art).unwrap(), "HI");
+    }
+
+    #[test]
+    fn unrecognized_glyphs_are_listed() {
+        let art = draw("AB", &SMALL).replacen(".##.", "####", 1);
+        let e = read_str(&format!("{art}\n")).unwrap_err();
+        assert_eq!(
+            e,
+            OcrError::Unrecognized(vec![Glyph {
+                index: 0,
+                art: "####\n#..#\n#..#\n####\n#..#\n#..#".to_string()
+            }])
+        );
+        assert!(e.to_string().starts_with("unrecognized glyph 0:\n####\n"));
+
+        let art = Grid::filled(7, 4, true);
+        assert_eq!(read(&art), Err(OcrError::Height(7)));
+        assert!(matches!(read_str("#x\n.."), Err(OcrError::Parse(_))));
+    }
+}
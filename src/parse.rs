@@ -0,0 +1,475 @@
+//! A [`ParseError`] shared by every day's parser, pointing at the offending text like a compiler diagnostic:
+//!
+//! ```text
+//! line 2, column 9: expected an amount, found `x`
+//!   |
+//! 2 | forward x
+//!   |         ^
+//! ```
+//!
+//! # Combinators
+//! Small [Parser]s for the pieces puzzle inputs are made of combine into a grammar for a whole input, and report
+//! every mistake as a [`ParseError`] at the right position:
+//!
+//! ```
+//! use aoc::parse::{key_values, pair, parse_all, sections, separated, number, number_grid, word};
+//!
+//! // A polymer template followed by pair insertion rules.
+//! let rules = parse_all("NNCB\n\nCH -> B\nHH -> N\n", pair(word(), "\n\n", key_values(word(), " -> ", word())));
+//! assert_eq!(rules.unwrap().1[1], ("HH".to_string(), "N".to_string()));
+//!
+//! // Drawn numbers followed by bingo boards.
+//! let bingo = pair(separated(number::<u8>(), ","), "\n\n", sections(number_grid::<u8>()));
+//! let e = parse_all("7,4,9\n\n 1 2\n 3 x\n", bingo).unwrap_err();
+//! assert_eq!((e.line, e.column, e.expected.as_str()), (4, 4, "a number"));
+//! ```
+use crate::{grid::Grid, Error};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// An error at a position of the puzzle input, with the text that was found there and a description of what was
+/// expected instead.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the input, starting at 1.
+    pub line: usize,
+    /// The column within the [line](Self::line) in characters, starting at 1.
+    pub column: usize,
+    /// The offending text, which is empty when something is missing.
+    pub found: String,
+    pub expected: String,
+    /// The whole input line, shown in the snippet.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// An error about `found`, which must be a slice of `input`.
+    ///
+    /// A `found` that is not part of `input` is reported at the end of the input.
+    pub fn new(input: &str, found: &str, expected: impl Into<String>) -> Self {
+        let offset = found
+            .as_ptr()
+            .addr()
+            .checked_sub(input.as_ptr().addr())
+            .filter(|offset| offset + found.len() <= input.len())
+            .unwrap_or(input.len());
+        Self::at(input, offset, found.len(), expected)
+    }
+
+    /// An error about the `len` bytes at byte `offset` of `input`.
+    ///
+    /// The offset and length are clamped to the input and to character boundaries.
+    pub fn at(input: &str, offset: usize, len: usize, expected: impl Into<String>) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let mut end = offset.saturating_add(len).min(input.len());
+        while !input.is_char_boundary(end) {
+            end += 1;
+        }
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let source_line = input[line_start..line_end].trim_end_matches('\r');
+
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            found: input[offset..end].to_string(),
+            expected: expected.into(),
+            source_line: source_line.to_string(),
+        }
+    }
+
+    /// Move the error down by `lines`, for errors found while parsing a part of the input that starts on a later line.
+    #[must_use]
+    pub fn shifted(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let found = self.found.lines().next().unwrap_or_default();
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if found.is_empty() {
+            writeln!(f, "nothing")?;
+        } else {
+            writeln!(f, "`{found}`")?;
+        }
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(found.chars().count().max(1))
+        )
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(Box::new(e))
+    }
+}
+
+/// The text being parsed by a [Parser], and the position reached in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Input<'a> {
+    #[must_use]
+    pub fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+
+    /// The text that is left to parse.
+    #[must_use]
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    /// An error at the current position about the next word, expecting `expected` instead.
+    #[must_use]
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let rest = self.rest();
+        let word = rest
+            .find(|c: char| c.is_whitespace() || c == ',')
+            .unwrap_or(rest.len());
+        let word = if word == 0 {
+            rest.chars()
+                .next()
+                .filter(|&c| c != '\n')
+                .map_or(0, char::len_utf8)
+        } else {
+            word
+        };
+        ParseError::at(self.text, self.pos, word, expected)
+    }
+
+    /// Skip `prefix` if the rest starts with it.
+    fn eat(&mut self, prefix: &str) -> bool {
+        let eaten = self.rest().starts_with(prefix);
+        if eaten {
+            self.pos += prefix.len();
+        }
+        eaten
+    }
+
+    /// The number of lines before the current position.
+    fn lines_before(&self) -> usize {
+        self.text[..self.pos].matches('\n').count()
+    }
+}
+
+/// A parser for a part of an [Input], which moves the position past what it parsed.
+///
+/// Every function `Fn(&mut Input) -> Result<T, ParseError>` is a [Parser], so a day can write its own for anything the
+/// combinators below do not cover.
+pub trait Parser<T>: Fn(&mut Input<'_>) -> Result<T, ParseError> {}
+
+impl<T, F: Fn(&mut Input<'_>) -> Result<T, ParseError>> Parser<T> for F {}
+
+/// Parse all of `text` with `parser`, allowing for a final newline.
+///
+/// # Errors
+/// Returns the [`ParseError`] of the `parser`, or an error at the first text it did not parse.
+pub fn parse_all<T>(text: &str, parser: impl Parser<T>) -> Result<T, ParseError> {
+    let mut input = Input::new(text);
+    let value = parser(&mut input)?;
+    input.eat("\n");
+    if input.is_empty() {
+        Ok(value)
+    } else {
+        Err(input.error("the end of the input"))
+    }
+}
+
+/// An unsigned decimal number.
+#[must_use]
+pub fn number<T: FromStr>() -> impl Parser<T> {
+    |input: &mut Input<'_>| {
+        let digits = input.rest().bytes().take_while(u8::is_ascii_digit).count();
+        integer(input, digits, "a number")
+    }
+}
+
+/// A decimal number with an optional `+` or `-` sign.
+#[must_use]
+pub fn signed<T: FromStr>() -> impl Parser<T> {
+    |input: &mut Input<'_>| {
+        let rest = input.rest().as_bytes();
+        let sign = usize::from(matches!(rest.first(), Some(b'+' | b'-')));
+        let digits = rest[sign..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        integer(
+            input,
+            if digits == 0 { 0 } else { sign + digits },
+            "a signed number",
+        )
+    }
+}
+
+/// Parse the first `len` bytes of the `input` as an integer.
+fn integer<T: FromStr>(input: &mut Input<'_>, len: usize, expected: &str) -> Result<T, ParseError> {
+    if len == 0 {
+        return Err(input.error(expected));
+    }
+    let value = input.rest()[..len]
+        .parse()
+        .map_err(|_| ParseError::at(input.text, input.pos, len, format!("{expected} in range")))?;
+    input.pos += len;
+    Ok(value)
+}
+
+/// Exactly the `text`.
+#[must_use]
+pub fn literal(text: &'static str) -> impl Parser<()> {
+    move |input: &mut Input<'_>| {
+        if input.eat(text) {
+            Ok(())
+        } else {
+            Err(input.error(format!("{text:?}")))
+        }
+    }
+}
+
+/// A word of letters, digits and underscores, such as a cave or an element.
+#[must_use]
+pub fn word() -> impl Parser<String> {
+    |input: &mut Input<'_>| {
+        let rest = input.rest();
+        let len = rest
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(input.error("a word"));
+        }
+        input.pos += len;
+        Ok(rest[..len].to_string())
+    }
+}
+
+/// The value of `parser` passed through `f`.
+pub fn map<T, U>(parser: impl Parser<T>, f: impl Fn(T) -> U) -> impl Parser<U> {
+    move |input: &mut Input<'_>| parser(input).map(&f)
+}
+
+/// A `first` and a `second` value with a `separator` literal in between, such as a key and its value.
+pub fn pair<A, B>(
+    first: impl Parser<A>,
+    separator: &'static str,
+    second: impl Parser<B>,
+) -> impl Parser<(A, B)> {
+    let separator = literal(separator);
+    move |input: &mut Input<'_>| {
+        let a = first(input)?;
+        separator(input)?;
+        Ok((a, second(input)?))
+    }
+}
+
+/// One or more `item`s with a `separator` literal in between, such as `1,2,3`.
+pub fn separated<T>(item: impl Parser<T>, separator: &'static str) -> impl Parser<Vec<T>> {
+    move |input: &mut Input<'_>| {
+        let mut items = vec![item(input)?];
+        while input.eat(separator) {
+            items.push(item(input)?);
+        }
+        Ok(items)
+    }
+}
+
+/// One or more lines with an `item` each, up to the end of the input or an empty line.
+///
+/// The newline at the end of the last line is left for the next parser.
+pub fn lines<T>(item: impl Parser<T>) -> impl Parser<Vec<T>> {
+    move |input: &mut Input<'_>| {
+        let mut items = vec![item(input)?];
+        loop {
+            let rest = input.rest();
+            if rest.is_empty() || rest == "\n" || rest.starts_with("\n\n") {
+                return Ok(items);
+            }
+            if !input.eat("\n") {
+                return Err(input.error("the end of the line"));
+            }
+            items.push(item(input)?);
+        }
+    }
+}
+
+/// One or more sections with an `item` each, separated by an empty line.
+pub fn sections<T>(item: impl Parser<T>) -> impl Parser<Vec<T>> {
+    separated(item, "\n\n")
+}
+
+/// One or more lines with a `key` and a `value` each, separated by a `separator` literal such as `": "` or `" -> "`,
+/// up to the end of the input or an empty line.
+pub fn key_values<K, V>(
+    key: impl Parser<K>,
+    separator: &'static str,
+    value: impl Parser<V>,
+) -> impl Parser<Vec<(K, V)>> {
+    lines(pair(key, separator, value))
+}
+
+/// A [Grid] with a cell per character, converted with `cell`, taking every line up to the end of the input or an
+/// empty line. It must start at the beginning of a line.
+pub fn grid<T>(
+    cell: impl Fn(char) -> Option<T> + Copy,
+    expected: &'static str,
+) -> impl Parser<Grid<T>> {
+    move |input: &mut Input<'_>| block(input, |text| Grid::parse_chars(text, cell, expected))
+}
+
+/// A [Grid] of whitespace-separated numbers such as a bingo board, taking every line up to the end of the input or an
+/// empty line. It must start at the beginning of a line.
+#[must_use]
+pub fn number_grid<T: FromStr>() -> impl Parser<Grid<T>> {
+    |input: &mut Input<'_>| block(input, Grid::parse_numbers)
+}
+
+/// Parse the lines up to the end of the `input` or an empty line with `parse`.
+fn block<T>(
+    input: &mut Input<'_>,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let rest = input.rest();
+    let len = rest.find("\n\n").unwrap_or(rest.len());
+    let len = if rest[..len].ends_with('\n') {
+        len - 1
+    } else {
+        len
+    };
+    if len == 0 {
+        return Err(input.error("a grid"));
+    }
+    let value = parse(&rest[..len]).map_err(|e| e.shifted(input.lines_before()))?;
+    input.pos += len;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        grid, key_values, lines, literal, map, number, pair, parse_all, separated, signed, word,
+        ParseError,
+    };
+
+    #[test]
+    fn position_of_a_slice() {
+        let input = "forward 5\nforward x\n";
+        let e = ParseError::new(input, &input[18..19], "an amount");
+
+        assert_eq!((e.line, e.column), (2, 9));
+        assert_eq!(e.found, "x");
+        assert_eq!(e.source_line, "forward x");
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 9: expected an amount, found `x`
+  |
+2 | forward x
+  |         ^"
+        );
+    }
+
+    #[test]
+    fn missing_text() {
+        let e = ParseError::at("up", 2, 0, "an amount").shifted(9);
+        assert_eq!((e.line, e.column), (10, 3));
+        assert_eq!(
+            e.to_string(),
+            "line 10, column 3: expected an amount, found nothing
+   |
+10 | up
+   |   ^"
+        );
+    }
+
+    #[test]
+    fn foreign_slice_is_reported_at_the_end() {
+        let e = ParseError::new("1\n2", "x", "a number");
+        assert_eq!((e.line, e.column), (2, 2));
+    }
+
+    #[test]
+    fn numbers_and_literals() {
+        assert_eq!(parse_all("42", number::<u8>()), Ok(42));
+        assert_eq!(parse_all("-7", signed::<i8>()), Ok(-7));
+        let e = parse_all("300", number::<u8>()).unwrap_err();
+        assert_eq!((e.column, e.found.as_str()), (1, "300"));
+        assert_eq!(e.expected, "a number in range");
+        let e = parse_all("-x", signed::<i8>()).unwrap_err();
+        assert_eq!((e.column, e.found.as_str()), (1, "-x"));
+
+        let target = pair(
+            map(pair(literal("x="), "", signed::<i32>()), |((), x)| x),
+            "..",
+            signed::<i32>(),
+        );
+        assert_eq!(parse_all("x=-5..10\n", target), Ok((-5, 10)));
+        let e = parse_all("x=1.10", pair(number::<u8>(), "..", number::<u8>())).unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (1, "a number"));
+        let e = parse_all("1.10", pair(number::<u8>(), "..", number::<u8>())).unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (2, "\"..\""));
+    }
+
+    #[test]
+    fn lists_and_lines() {
+        let caves = lines(pair(word(), "-", word()));
+        let parsed = parse_all("start-A\nA-end\n", &caves).unwrap();
+        assert_eq!(parsed[1], ("A".to_string(), "end".to_string()));
+
+        let e = parse_all("start-A\nA end\n", &caves).unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, " "));
+        let e = parse_all("1,2,3 4", separated(number::<u8>(), ",")).unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (6, "the end of the input"));
+    }
+
+    #[test]
+    fn key_value_lines() {
+        let positions = key_values(word(), ": ", number::<u8>());
+        let parsed = parse_all("first: 4\nsecond: 8\n", &positions).unwrap();
+        assert_eq!(
+            parsed,
+            [("first".to_string(), 4), ("second".to_string(), 8)]
+        );
+
+        let e = parse_all("first: 4\nsecond 8\n", &positions).unwrap_err();
+        assert_eq!((e.line, e.column, e.expected.as_str()), (2, 7, "\": \""));
+        let e = parse_all("first: 4\nsecond: x\n", &positions).unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 9, "x"));
+    }
+
+    #[test]
+    fn grids() {
+        let image = pair(word(), "\n\n", grid(|c| Some(c == '#'), "`#` or `.`"));
+        let (_, pixels) = parse_all("algorithm\n\n#.\n.#\n", &image).unwrap();
+        assert_eq!(pixels.diagonal().filter(|&&lit| lit).count(), 2);
+
+        let e = parse_all("algorithm\n\n#.\n.#.\n", &image).unwrap_err();
+        assert_eq!((e.line, e.expected.as_str()), (4, "a row of 2 characters"));
+    }
+}
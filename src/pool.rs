@@ -0,0 +1,124 @@
+//! A small pool of worker threads for running independent jobs, such as whole days, concurrently.
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, MutexGuard, PoisonError,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A job that panicked, with the message it panicked with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panicked(pub String);
+
+/// The outcome of one job run by [map].
+#[derive(Debug)]
+pub struct Done<R> {
+    pub result: Result<R, Panicked>,
+    /// The time the job kept its worker busy.
+    pub elapsed: Duration,
+}
+
+/// Apply `job` to every item on up to `jobs` worker threads and return the outcomes in the order of the `items`.
+///
+/// Workers take the next item as soon as they finish one, so slow items do not hold up the others. A panicking job
+/// only fails its own item.
+pub fn map<T, R, F>(items: Vec<T>, jobs: usize, job: F) -> Vec<Done<R>>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let count = items.len();
+    let items: Vec<Mutex<Option<T>>> = items.into_iter().map(|i| Mutex::new(Some(i))).collect();
+    let done: Vec<Mutex<Option<Done<R>>>> = (0..count).map(|_| Mutex::new(None)).collect();
+    let next = AtomicUsize::new(0);
+
+    let work = || loop {
+        let i = next.fetch_add(1, Ordering::Relaxed);
+        let Some(item) = items.get(i).and_then(|item| lock(item).take()) else {
+            break;
+        };
+        let start = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| job(item)))
+            .map_err(|payload| Panicked(message(payload.as_ref())));
+        *lock(&done[i]) = Some(Done {
+            result,
+            elapsed: start.elapsed(),
+        });
+    };
+
+    let workers = jobs.clamp(1, count.max(1));
+    if workers == 1 {
+        work();
+    } else {
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(work);
+            }
+        });
+    }
+
+    // Every worker runs until there are no items left, so every item has an outcome by now.
+    done.into_iter()
+        .filter_map(|d| d.into_inner().unwrap_or_else(PoisonError::into_inner))
+        .collect()
+}
+
+/// Lock a `mutex` that no job ever holds while panicking, so poisoning cannot happen.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// The message of a panic `payload`, which is a `&str` or `String` for the `panic!` macro.
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map, Panicked};
+    use std::{sync::Barrier, thread, time::Duration};
+
+    #[test]
+    fn results_are_in_order_and_panics_are_isolated() {
+        let done = map((0..20u64).collect(), 4, |i| {
+            thread::sleep(Duration::from_millis(20 - i));
+            assert!(i != 7, "seven is unlucky");
+            i * 2
+        });
+
+        assert_eq!(done.len(), 20);
+        for (i, d) in (0..20).zip(&done) {
+            if i == 7 {
+                assert_eq!(d.result, Err(Panicked("seven is unlucky".to_string())));
+            } else {
+                assert_eq!(d.result, Ok(i * 2));
+            }
+        }
+    }
+
+    #[test]
+    fn jobs_run_concurrently() {
+        // No job gets past the barrier before all four are running at the same time, so this would hang if they
+        // were run one after the other.
+        let barrier = Barrier::new(4);
+        let done = map(vec![(); 4], 4, |()| {
+            barrier.wait();
+            thread::sleep(Duration::from_millis(10));
+        });
+        let busy: Duration = done.iter().map(|d| d.elapsed).sum();
+        assert!(busy >= Duration::from_millis(40), "{busy:?}");
+
+        assert!(map(Vec::<u8>::new(), 0, |i| i).is_empty());
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{
+    alloc::{self, Usage},
+    bench::{self, Measurement},
+    differential::{self, Disagreement},
+    Answer, Error, Result, Solution,
+};
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// One of the two [Part]s of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl TryFrom<&str> for Part {
+    type Error = String;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            p => Err(format!("part must be 1 or 2, got {p}")),
+        }
+    }
+}
+
+/// The outcome of solving a single [Part] of a day's puzzle.
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+    pub memory: Usage,
+}
+
+/// The outcome of parsing a day's input and solving the requested [Part]s.
+#[derive(Debug)]
+pub struct DayRun {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Duration,
+    pub parse_memory: Usage,
+    pub parts: Vec<PartRun>,
+}
+
+/// A type-erased [Solution] stored in the [DAYS] registry.
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(&str, &[Part]) -> Result<DayRun>,
+    check_examples: fn(Part) -> Option<bool>,
+    bench: fn(&str, &bench::Config) -> Result<Vec<Measurement>>,
+    alternatives: fn() -> Vec<(Part, &'static str)>,
+    differential: fn(&str) -> Result<Vec<Disagreement>>,
+}
+
+impl Entry {
+    #[must_use]
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: solve::<S>,
+            check_examples: check_examples::<S>,
+            bench: bench::bench::<S>,
+            alternatives: alternatives::<S>,
+            differential: differential::check::<S>,
+        }
+    }
+
+    /// Parse the raw puzzle `input` and solve each of the requested `parts`, timing every step.
+    ///
+    /// # Errors
+    /// Returns an error when the `input` cannot be parsed. Failures of individual parts are reported in the [`DayRun`].
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<DayRun> {
+        (self.solve)(input, parts)
+    }
+
+    /// Solve every [Example](crate::Example) that states an answer for `part` and compare the answers.
+    ///
+    /// Returns `None` when there is no such example or the part is not implemented yet, and whether all of them matched
+    /// otherwise.
+    #[must_use]
+    pub fn check_examples(&self, part: Part) -> Option<bool> {
+        (self.check_examples)(part)
+    }
+
+    /// Benchmark parsing `input` and solving each implemented part.
+    ///
+    /// # Errors
+    /// Returns an error when parsing or a part fails.
+    pub fn bench(&self, input: &str, config: &bench::Config) -> Result<Vec<Measurement>> {
+        (self.bench)(input, config)
+    }
+
+    /// The part and name of every alternative [Implementation](crate::Implementation) of the day.
+    #[must_use]
+    pub fn alternatives(&self) -> Vec<(Part, &'static str)> {
+        (self.alternatives)()
+    }
+
+    /// Run every alternative implementation on `input` and report where it disagrees with the main one.
+    ///
+    /// # Errors
+    /// Returns an error when the `input` cannot be parsed.
+    pub fn differential(&self, input: &str) -> Result<Vec<Disagreement>> {
+        (self.differential)(input)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<DayRun> {
+    let start = Instant::now();
+    let (parsed, parse_memory) = alloc::measure(|| S::parse(input));
+    let parse = start.elapsed();
+    let parsed = parsed?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, memory) = alloc::measure(|| solve_part::<S>(&parsed, part));
+            let elapsed = start.elapsed();
+            PartRun {
+                part,
+                answer,
+                elapsed,
+                memory,
+            }
+        })
+        .collect();
+
+    Ok(DayRun {
+        year: S::YEAR,
+        day: S::DAY,
+        parse,
+        parse_memory,
+        parts,
+    })
+}
+
+pub(crate) fn solve_part<S: Solution>(input: &S::Input, part: Part) -> Result<Answer> {
+    let answer = match part {
+        Part::One => S::part1(input),
+        Part::Two => S::part2(input),
+    };
+    answer.map(Into::into)
+}
+
+fn alternatives<S: Solution>() -> Vec<(Part, &'static str)> {
+    S::ALTERNATIVES.iter().map(|a| (a.part, a.name)).collect()
+}
+
+fn check_examples<S: Solution>(part: Part) -> Option<bool> {
+    let mut passed = None;
+    for example in crate::examples::of::<S>() {
+        let Some(expected) = example.answer(part) else {
+            continue;
+        };
+        let answer = S::parse(&example.input).and_then(|input| solve_part::<S>(&input, part));
+        if matches!(answer, Err(Error::Unimplemented)) {
+            return None;
+        }
+        let matched = answer.is_ok_and(|answer| answer == Answer::parse(expected));
+        passed = Some(passed.unwrap_or(true) && matched);
+    }
+    passed
+}
+
+/// Every day's [Solution] that is compiled in by its feature, in year and day order.
+///
+/// The features of the days of [YEAR](crate::YEAR) are `day<N>`, and those of other years `y<year>-day<N>`.
+pub const DAYS: &[Entry] = &[
+    #[cfg(feature = "day1")]
+    Entry::new::<crate::day1::Day1>(),
+    #[cfg(feature = "day2")]
+    Entry::new::<crate::day2::Day2>(),
+    #[cfg(feature = "day3")]
+    Entry::new::<crate::day3::Day3>(),
+    #[cfg(feature = "day4")]
+    Entry::new::<crate::day4::Day4>(),
+    #[cfg(feature = "day5")]
+    Entry::new::<crate::day5::Day5>(),
+    #[cfg(feature = "day6")]
+    Entry::new::<crate::day6::Day6>(),
+    #[cfg(feature = "day7")]
+    Entry::new::<crate::day7::Day7>(),
+    #[cfg(feature = "day8")]
+    Entry::new::<crate::day8::Day8>(),
+    #[cfg(feature = "day9")]
+    Entry::new::<crate::day9::Day9>(),
+    #[cfg(feature = "day10")]
+    Entry::new::<crate::day10::Day10>(),
+    #[cfg(feature = "day11")]
+    Entry::new::<crate::day11::Day11>(),
+    #[cfg(feature = "day12")]
+    Entry::new::<crate::day12::Day12>(),
+    #[cfg(feature = "day13")]
+    Entry::new::<crate::day13::Day13>(),
+    #[cfg(feature = "day14")]
+    Entry::new::<crate::day14::Day14>(),
+    #[cfg(feature = "day15")]
+    Entry::new::<crate::day15::Day15>(),
+    #[cfg(feature = "day16")]
+    Entry::new::<crate::day16::Day16>(),
+    #[cfg(feature = "day17")]
+    Entry::new::<crate::day17::Day17>(),
+    #[cfg(feature = "day18")]
+    Entry::new::<crate::day18::Day18>(),
+    #[cfg(feature = "day19")]
+    Entry::new::<crate::day19::Day19>(),
+    #[cfg(feature = "day20")]
+    Entry::new::<crate::day20::Day20>(),
+    #[cfg(feature = "day21")]
+    Entry::new::<crate::day21::Day21>(),
+    #[cfg(feature = "day22")]
+    Entry::new::<crate::day22::Day22>(),
+    #[cfg(feature = "day23")]
+    Entry::new::<crate::day23::Day23>(),
+    #[cfg(feature = "day24")]
+    Entry::new::<crate::day24::Day24>(),
+    #[cfg(feature = "day25")]
+    Entry::new::<crate::day25::Day25>(),
+];
+
+/// Find the registry [Entry] for `day` of `year`.
+///
+/// # Errors
+/// Returns ``UnknownDay`` when no [Solution] is registered for `day` of `year`.
+pub fn find(year: u16, day: u8) -> Result<&'static Entry> {
+    DAYS.iter()
+        .find(|e| e.year == year && e.day == day)
+        .ok_or(Error::UnknownDay { year, day })
+}
+
+/// The registered days of `year`, in day order.
+pub fn days(year: u16) -> impl Iterator<Item = &'static Entry> {
+    DAYS.iter().filter(move |e| e.year == year)
+}
+
+/// Every year with a registered day, in order.
+#[must_use]
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = DAYS.iter().map(|e| e.year).collect();
+    years.dedup();
+    years
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{days, find, years, DAYS};
+    use crate::{Error, YEAR};
+
+    #[test]
+    fn registry_is_in_day_order() {
+        let days: Vec<(u16, u8)> = DAYS.iter().map(|e| (e.year, e.day)).collect();
+        assert!(days.windows(2).all(|w| w[0] < w[1]), "{days:?}");
+        #[cfg(feature = "all")]
+        {
+            let days: Vec<u8> = super::days(YEAR).map(|e| e.day).collect();
+            assert_eq!(days, (1..=25).collect::<Vec<u8>>());
+            assert!(years().contains(&YEAR));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "day1")]
+    fn solve_day1_example() {
+        use crate::Part;
+
+        let run = find(YEAR, 1)
+            .unwrap()
+            .solve(
+                "199\n200\n208\n210\n200\n207\n240\n269\n260\n263",
+                &Part::ALL,
+            )
+            .unwrap();
+
+        assert_eq!(run.day, 1);
+        assert_eq!(run.parts[0].answer.as_ref().unwrap().to_string(), "7");
+        assert_eq!(run.parts[1].answer.as_ref().unwrap().to_string(), "5");
+    }
+
+    #[test]
+    #[cfg(all(feature = "day1", feature = "day2", feature = "day3", feature = "day5"))]
+    fn examples() {
+        use crate::Part;
+
+        assert_eq!(find(YEAR, 1).unwrap().check_examples(Part::One), Some(true));
+        assert_eq!(find(YEAR, 2).unwrap().check_examples(Part::Two), Some(true));
+        assert_eq!(find(YEAR, 3).unwrap().check_examples(Part::One), Some(true));
+        // Part 2 of day 3 is not implemented, although its example states an answer.
+        assert_eq!(find(YEAR, 3).unwrap().check_examples(Part::Two), None);
+        assert_eq!(find(YEAR, 5).unwrap().check_examples(Part::One), None);
+    }
+
+    #[test]
+    fn unknown_day() {
+        assert!(matches!(
+            find(YEAR, 26),
+            Err(Error::UnknownDay {
+                year: YEAR,
+                day: 26
+            })
+        ));
+        assert!(matches!(
+            find(2015, 1),
+            Err(Error::UnknownDay { year: 2015, day: 1 })
+        ));
+        assert_eq!(days(2015).count(), 0);
+        assert!(!years().contains(&2015));
+    }
+}
@@ -0,0 +1,400 @@
+//! The results of running days as a table of [Row]s, written as text for people or as JSON or CSV for other tools.
+//!
+//! The JSON and CSV schemas are stable: every [Row] has a `year`, `day`, `part`, `answer`, `parse_ns`, `solve_ns` and
+//! `error`, where the times are whole nanoseconds and missing values are `null` in JSON and empty in CSV. The `answer` is always
+//! the [canonical](Answer::canonical) text of the answer, a string in JSON, so art comes out as the letters it spells.
+//!
+//! When allocations are counted (see [`alloc`]), every row also has the memory usage of parsing and solving: a
+//! `parse_memory` and `solve_memory` object of `allocations`, `bytes` and `peak_bytes` in JSON, and the `parse_allocs`,
+//! `parse_bytes`, `parse_peak_bytes`, `solve_allocs`, `solve_bytes` and `solve_peak_bytes` columns in CSV.
+//!
+//! Version 2 of the JSON document added the `year`, which is also the first CSV column since then.
+use crate::{
+    alloc::{self, Usage},
+    json::Value,
+    registry::DayRun,
+    Answer, Error, Part,
+};
+use std::{
+    fmt,
+    io::{self, Write},
+    time::Duration,
+};
+
+/// The output [Format] of the runner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// An aligned table.
+    #[default]
+    Text,
+    /// A `{"version": 2, "results": [...]}` document.
+    Json,
+    /// A header line followed by one line per [Row].
+    Csv,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            f => Err(format!("format must be text, json or csv, got {f}")),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::Text => f.pad("text"),
+            Format::Json => f.pad("json"),
+            Format::Csv => f.pad("csv"),
+        }
+    }
+}
+
+/// The result of one part of a day: its answer and timings, or the error that prevented it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    /// The time spent parsing the day's input, shared by both parts.
+    pub parse: Option<Duration>,
+    pub solve: Option<Duration>,
+    pub error: Option<String>,
+    /// The memory [Usage] of parsing, when allocations are counted.
+    pub parse_memory: Option<Usage>,
+    /// The memory [Usage] of solving, when allocations are counted.
+    pub solve_memory: Option<Usage>,
+}
+
+impl Row {
+    /// The [Row]s of the parts of a [`DayRun`]. Parts that are not implemented yet are left out.
+    #[must_use]
+    pub fn from_run(run: &DayRun) -> Vec<Self> {
+        run.parts
+            .iter()
+            .filter(|p| !matches!(p.answer, Err(Error::Unimplemented)))
+            .map(|p| Row {
+                year: run.year,
+                day: run.day,
+                part: p.part,
+                answer: p.answer.as_ref().ok().cloned(),
+                parse: Some(run.parse),
+                solve: Some(p.elapsed),
+                error: p.answer.as_ref().err().map(ToString::to_string),
+                parse_memory: alloc::ENABLED.then_some(run.parse_memory),
+                solve_memory: alloc::ENABLED.then_some(p.memory),
+            })
+            .collect()
+    }
+
+    /// The [Row]s of `parts` of a `day` of `year` that failed before any part could be solved.
+    #[must_use]
+    pub fn failed(year: u16, day: u8, parts: &[Part], error: &Error) -> Vec<Self> {
+        parts
+            .iter()
+            .map(|&part| Row {
+                year,
+                day,
+                part,
+                answer: None,
+                parse: None,
+                solve: None,
+                error: Some(error.to_string()),
+                parse_memory: None,
+                solve_memory: None,
+            })
+            .collect()
+    }
+
+    /// The row as a JSON object, with the memory fields when `counted`.
+    fn to_json(&self, counted: bool) -> Value {
+        let nanos = |d: Option<Duration>| {
+            d.map_or(Value::Null, |d| {
+                Value::Integer(i128::try_from(d.as_nanos()).unwrap_or(i128::MAX))
+            })
+        };
+        let part: u8 = match self.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        let memory = |usage: Option<Usage>| {
+            usage.map_or(Value::Null, |u| {
+                Value::object([
+                    ("allocations", Value::from(u.allocations as u64)),
+                    ("bytes", Value::from(u.bytes as u64)),
+                    ("peak_bytes", Value::from(u.peak as u64)),
+                ])
+            })
+        };
+        let mut fields = vec![
+            ("year", Value::from(self.year)),
+            ("day", Value::from(self.day)),
+            ("part", Value::from(part)),
+            (
+                "answer",
+                Value::from(self.answer.as_ref().map(Answer::canonical)),
+            ),
+            ("parse_ns", nanos(self.parse)),
+            ("solve_ns", nanos(self.solve)),
+            ("error", Value::from(self.error.clone())),
+        ];
+        if counted {
+            fields.push(("parse_memory", memory(self.parse_memory)));
+            fields.push(("solve_memory", memory(self.solve_memory)));
+        }
+        Value::object(fields)
+    }
+
+    fn counted(rows: &[Row]) -> bool {
+        rows.iter()
+            .any(|r| r.parse_memory.is_some() || r.solve_memory.is_some())
+    }
+}
+
+/// Write `rows` to `out` in the given [Format].
+///
+/// # Errors
+/// Returns an error when writing to `out` fails.
+pub fn write(format: Format, rows: &[Row], out: &mut impl Write) -> io::Result<()> {
+    let counted = Row::counted(rows);
+    match format {
+        Format::Text => {
+            let memory = |parse: &dyn fmt::Display, solve: &dyn fmt::Display| {
+                if counted {
+                    format!("  {parse:>24}  {solve:>24}")
+                } else {
+                    String::new()
+                }
+            };
+            writeln!(
+                out,
+                "{:>3}  {:>4}  {:<20}  {:>12}  {:>12}{}",
+                "day",
+                "part",
+                "answer",
+                "parse",
+                "solve",
+                memory(&"parse memory", &"solve memory"),
+            )?;
+            for row in rows {
+                let answer = match (&row.answer, &row.error) {
+                    (Some(answer), _) => answer.to_string(),
+                    (None, Some(e)) => format!("error: {e}"),
+                    (None, None) => String::new(),
+                };
+                let time = |d: Option<Duration>| d.map(|d| format!("{d:?}")).unwrap_or_default();
+                let usage = |u: Option<Usage>| u.map(|u| u.to_string()).unwrap_or_default();
+                writeln!(
+                    out,
+                    "{:>3}  {:>4}  {:<20}  {:>12}  {:>12}{}",
+                    row.day,
+                    row.part,
+                    answer,
+                    time(row.parse),
+                    time(row.solve),
+                    memory(&usage(row.parse_memory), &usage(row.solve_memory)),
+                )?;
+            }
+        }
+        Format::Json => {
+            let results = rows.iter().map(|row| row.to_json(counted)).collect();
+            let document = Value::object([
+                ("version", Value::from(2u8)),
+                ("results", Value::Array(results)),
+            ]);
+            writeln!(out, "{document}")?;
+        }
+        Format::Csv => {
+            write!(out, "year,day,part,answer,parse_ns,solve_ns,error")?;
+            if counted {
+                write!(out, ",parse_allocs,parse_bytes,parse_peak_bytes")?;
+                write!(out, ",solve_allocs,solve_bytes,solve_peak_bytes")?;
+            }
+            writeln!(out)?;
+            for row in rows {
+                let nanos =
+                    |d: Option<Duration>| d.map(|d| d.as_nanos().to_string()).unwrap_or_default();
+                write!(
+                    out,
+                    "{},{},{},{},{},{},{}",
+                    row.year,
+                    row.day,
+                    row.part,
+                    csv_field(
+                        &row.answer
+                            .as_ref()
+                            .map(Answer::canonical)
+                            .unwrap_or_default()
+                    ),
+                    nanos(row.parse),
+                    nanos(row.solve),
+                    csv_field(row.error.as_deref().unwrap_or_default()),
+                )?;
+                if counted {
+                    for usage in [row.parse_memory, row.solve_memory] {
+                        match usage {
+                            Some(u) => write!(out, ",{},{},{}", u.allocations, u.bytes, u.peak)?,
+                            None => write!(out, ",,,")?,
+                        }
+                    }
+                }
+                writeln!(out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Quote a CSV `field` when it contains a separator, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write, Format, Row};
+    use crate::{alloc::Usage, Answer, Error, Part};
+    use std::time::Duration;
+
+    fn rows() -> Vec<Row> {
+        let mut rows = vec![Row {
+            year: 2021,
+            day: 1,
+            part: Part::One,
+            answer: Some(Answer::from(7u8)),
+            parse: Some(Duration::from_nanos(1500)),
+            solve: Some(Duration::from_nanos(250)),
+            error: None,
+            parse_memory: None,
+            solve_memory: None,
+        }];
+        rows.extend(Row::failed(
+            2021,
+            2,
+            &[Part::Two],
+            &Error::Solve("bad \"thing\",\nhere".into()),
+        ));
+        rows
+    }
+
+    fn written(format: Format) -> String {
+        written_rows(format, &rows())
+    }
+
+    fn written_rows(format: Format, rows: &[Row]) -> String {
+        let mut out = Vec::new();
+        write(format, rows, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            written(Format::Json),
+            concat!(
+                r#"{"version":2,"results":["#,
+                r#"{"year":2021,"day":1,"part":1,"answer":"7","parse_ns":1500,"solve_ns":250,"error":null},"#,
+                r#"{"year":2021,"day":2,"part":2,"answer":null,"parse_ns":null,"solve_ns":null,"error":"solving failed: bad \"thing\",\nhere"}"#,
+                "]}\n"
+            )
+        );
+
+        // Art is written as the letters it spells, as it would be submitted.
+        let mut rows = rows();
+        rows[0].answer = Some(Answer::parse(
+            "#..#..###\n#..#...#.\n####...#.\n#..#...#.\n#..#...#.\n#..#..###\n",
+        ));
+        assert!(written_rows(Format::Json, &rows).contains(r#""answer":"HI""#));
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            written(Format::Csv),
+            "year,day,part,answer,parse_ns,solve_ns,error\n2021,1,1,7,1500,250,\n2021,2,2,,,,\"solving failed: bad \"\"thing\"\",\nhere\"\n"
+        );
+    }
+
+    #[test]
+    fn text() {
+        let text = written(Format::Text);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines[0],
+            "day  part  answer                       parse         solve"
+        );
+        assert_eq!(
+            lines[1],
+            "  1     1  7                            1.5µs         250ns"
+        );
+        assert!(lines[2].starts_with("  2     2  error: solving failed"));
+    }
+
+    #[test]
+    fn rows_of_other_years() {
+        let mut rows = rows();
+        rows[0].year = 2022;
+
+        let json = written_rows(Format::Json, &rows);
+        assert!(json.contains(r#"{"year":2022,"day":1,"part":1,"#));
+        assert!(json.contains(r#"{"year":2021,"day":2,"part":2,"#));
+
+        let csv = written_rows(Format::Csv, &rows);
+        assert_eq!(csv.lines().nth(1), Some("2022,1,1,7,1500,250,"));
+    }
+
+    #[test]
+    fn memory_columns_when_counted() {
+        let mut rows = rows();
+        rows[0].parse_memory = Some(Usage {
+            allocations: 3,
+            bytes: 2048,
+            peak: 1024,
+        });
+        rows[0].solve_memory = Some(Usage::default());
+
+        let json = written_rows(Format::Json, &rows);
+        assert!(json.contains(r#""error":null,"parse_memory":{"allocations":3,"bytes":2048,"peak_bytes":1024},"solve_memory":{"allocations":0,"bytes":0,"peak_bytes":0}}"#));
+        assert!(json.contains(r#""parse_memory":null,"solve_memory":null}"#));
+
+        let csv = written_rows(Format::Csv, &rows);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].ends_with(",error,parse_allocs,parse_bytes,parse_peak_bytes,solve_allocs,solve_bytes,solve_peak_bytes"));
+        assert_eq!(lines[1], "2021,1,1,7,1500,250,,3,2048,1024,0,0,0");
+        assert!(csv.ends_with("here\",,,,,,\n"));
+
+        let text = written_rows(Format::Text, &rows);
+        assert!(text.lines().nth(1).unwrap().ends_with(concat!(
+            "       3 / 2.0KiB / 1.0KiB",
+            "               0 / 0B / 0B"
+        )));
+    }
+
+    #[test]
+    #[cfg(feature = "day3")]
+    fn rows_of_a_run_skip_unimplemented_parts() {
+        use crate::registry::find;
+
+        let run = find(crate::YEAR, 3)
+            .unwrap()
+            .solve("00100\n11110", &Part::ALL)
+            .unwrap();
+        let rows = Row::from_run(&run);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].year, crate::YEAR);
+        assert_eq!(rows[0].part, Part::One);
+        assert_eq!(Format::try_from("csv"), Ok(Format::Csv));
+        assert!(Format::try_from("xml").is_err());
+    }
+}
@@ -0,0 +1,710 @@
+use crate::{answers::Answers, YEAR};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ScaffoldError {
+    #[error("day must be between 1 and 25, got {0}")]
+    InvalidDay(u8),
+    #[error("year must be {} or later, got {0}", crate::FIRST_YEAR)]
+    InvalidYear(u16),
+    #[error("day {0} already has a solution; pass --force to overwrite it")]
+    Exists(u8),
+    #[error("could not find where to register day {day} in {}", .path.display())]
+    NoInsertionPoint { day: u8, path: PathBuf },
+    #[error("accessing {} failed", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// What [`Scaffold::generate`] did for a day.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Created,
+    Overwritten,
+    Skipped,
+}
+
+/// Scaffolds new days into the crate rooted at `root` from the templates in `root/templates`.
+///
+/// A day is made of `mod.rs`, `main.rs` and an empty `input.txt` in its [directory](crate::day_dir) below `src/`, and
+/// is registered as a module, an entry in `src/registry.rs`, a binary and a feature in `Cargo.toml` and status rows in
+/// `README.md`. The module, entry and binary are only compiled in with the feature.
+///
+/// The days of [YEAR] are modules of `src/lib.rs` with `day<N>` features. The days of other years are modules of a
+/// module per year, such as `src/y2022/mod.rs`, with features such as `y2022-day4`, and the year gets an empty answer
+/// store of its own.
+pub struct Scaffold {
+    root: PathBuf,
+    year: u16,
+    force: bool,
+}
+
+impl Scaffold {
+    pub const MOD_TEMPLATE: &'static str = "templates/mod.rs.tmpl";
+    pub const MAIN_TEMPLATE: &'static str = "templates/main.rs.tmpl";
+
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>, year: u16, force: bool) -> Self {
+        Self {
+            root: root.into(),
+            year,
+            force,
+        }
+    }
+
+    /// Scaffold `day` unless it already has a solution.
+    ///
+    /// Registration is idempotent, so running it again for an already registered day leaves the crate untouched.
+    ///
+    /// # Errors
+    /// Returns ``InvalidDay`` or ``InvalidYear`` for a day that cannot exist, ``Exists`` when the day already has a solution and `force` is not set, and ``Io`` when any file cannot be read or written.
+    pub fn generate(&self, day: u8) -> Result<Outcome, ScaffoldError> {
+        if !(1..=25).contains(&day) {
+            return Err(ScaffoldError::InvalidDay(day));
+        }
+        if self.year < crate::FIRST_YEAR {
+            return Err(ScaffoldError::InvalidYear(self.year));
+        }
+        let day = Day {
+            year: self.year,
+            day,
+        };
+
+        let dir = self
+            .root
+            .join("src")
+            .join(crate::day_dir(day.year, day.day));
+        let module = dir.join("mod.rs");
+        let exists = module.exists();
+        if exists && !self.force {
+            return Err(ScaffoldError::Exists(day.day));
+        }
+
+        create_dir(&dir)?;
+        let template = read(&self.root.join(Self::MOD_TEMPLATE))?;
+        write(&module, &render(&template, day.day, day.year))?;
+        let template = read(&self.root.join(Self::MAIN_TEMPLATE))?;
+        write(&dir.join("main.rs"), &render(&template, day.day, day.year))?;
+        let input = dir.join("input.txt");
+        if !input.exists() {
+            write(&input, "")?;
+        }
+
+        if day.year == YEAR {
+            self.register("src/lib.rs", day, register_module)?;
+        } else {
+            self.create_year(day.day)?;
+            let modules = format!("src/y{}/mod.rs", day.year);
+            self.register(&modules, day, register_module)?;
+        }
+        self.register("src/registry.rs", day, register_entry)?;
+        self.register("Cargo.toml", day, register_manifest)?;
+        self.register("README.md", day, register_status)?;
+
+        Ok(if exists {
+            Outcome::Overwritten
+        } else {
+            Outcome::Created
+        })
+    }
+
+    /// Generate `day` only when it does not have a solution yet.
+    ///
+    /// # Errors
+    /// Returns ``Io`` when any file cannot be read or written.
+    pub fn generate_missing(&self, day: u8) -> Result<Outcome, ScaffoldError> {
+        match self.generate(day) {
+            Err(ScaffoldError::Exists(_)) => Ok(Outcome::Skipped),
+            outcome => outcome,
+        }
+    }
+
+    /// Create the module and the answer store of a year other than [YEAR], unless they exist, and declare the
+    /// module in `src/lib.rs`, on the way to scaffolding `day`.
+    fn create_year(&self, day: u8) -> Result<(), ScaffoldError> {
+        let year = self.year;
+        let modules = self.root.join(format!("src/y{year}/mod.rs"));
+        if !modules.exists() {
+            write(
+                &modules,
+                &format!("//! # Advent of Code {year}\n//! Solutions for [Advent of Code {year}](https://adventofcode.com/{year}).\n"),
+            )?;
+        }
+
+        let answers = self.root.join(Answers::store(year));
+        if !answers.exists() {
+            create_dir(&self.root.join("answers"))?;
+            write(&answers, "")?;
+        }
+
+        let path = self.root.join("src/lib.rs");
+        let lib = read(&path)?;
+        if lib.contains(&format!("pub mod y{year};")) {
+            return Ok(());
+        }
+        match register_year(&lib, year) {
+            Some(lib) => write(&path, &lib),
+            None => Err(ScaffoldError::NoInsertionPoint { day, path }),
+        }
+    }
+
+    fn register(
+        &self,
+        file: &str,
+        day: Day,
+        insert: fn(&str, Day) -> Option<String>,
+    ) -> Result<(), ScaffoldError> {
+        let path = self.root.join(file);
+        let contents = read(&path)?;
+        if is_registered(file, &contents, day) {
+            return Ok(());
+        }
+        match insert(&contents, day) {
+            Some(contents) => write(&path, &contents),
+            None => Err(ScaffoldError::NoInsertionPoint { day: day.day, path }),
+        }
+    }
+}
+
+/// A [Day] of a year, ordered by year first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Day {
+    year: u16,
+    day: u8,
+}
+
+impl Day {
+    /// The path of the day's module below the crate root, such as `day4` or `y2022::day4`.
+    fn module(self) -> String {
+        if self.year == YEAR {
+            format!("day{}", self.day)
+        } else {
+            format!("y{}::day{}", self.year, self.day)
+        }
+    }
+
+    /// The name of the day's feature and binary, such as `day4` or `y2022-day4`.
+    fn feature(self) -> String {
+        if self.year == YEAR {
+            format!("day{}", self.day)
+        } else {
+            format!("y{}-day{}", self.year, self.day)
+        }
+    }
+
+    /// The [Day] of a `feature` name.
+    fn of_feature(feature: &str) -> Option<Self> {
+        let (year, day) = match feature.strip_prefix('y') {
+            Some(rest) => {
+                let (year, day) = rest.split_once('-')?;
+                (year.parse().ok()?, day)
+            }
+            None => (YEAR, feature),
+        };
+        let day = day.strip_prefix("day")?.parse().ok()?;
+        Some(Self { year, day })
+    }
+
+    /// The name of the day in the status rows, such as `Day 4` or `2022 Day 4`, as written by
+    /// [`PartStatus`](crate::status::PartStatus).
+    fn status(self) -> String {
+        if self.year == YEAR {
+            format!("Day {}", self.day)
+        } else {
+            format!("{} Day {}", self.year, self.day)
+        }
+    }
+}
+
+/// Substitute `{day}`, `{year}` and the `{module}` path of the day in a `template`.
+#[must_use]
+pub fn render(template: &str, day: u8, year: u16) -> String {
+    template
+        .replace("{module}", &Day { year, day }.module())
+        .replace("{day}", &day.to_string())
+        .replace("{year}", &year.to_string())
+}
+
+fn is_registered(file: &str, contents: &str, day: Day) -> bool {
+    if file == "README.md" {
+        // "Day 4 Part 1" is also part of "2022 Day 4 Part 1", so the rows are read rather than searched.
+        return contents.lines().any(|l| status_row(l) == Some((day, 1)));
+    }
+    let needle = match file {
+        "src/registry.rs" => format!("crate::{}::Day{}>()", day.module(), day.day),
+        "Cargo.toml" => format!("name = \"{}\"", day.feature()),
+        // `src/lib.rs` or the module of a year.
+        _ => format!("pub mod day{};", day.day),
+    };
+    contents.contains(&needle)
+}
+
+/// Insert `line` among the lines that `number` recognizes as belonging to a day, keeping them ordered by `key`.
+///
+/// The `line` may span several lines, in which case it is ordered by its first.
+fn insert_line<N, K: Ord>(
+    contents: &str,
+    line: &str,
+    number: impl Fn(&str) -> Option<N>,
+    key: impl Fn(&str) -> K,
+) -> Option<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let first = lines.iter().position(|l| number(l).is_some())?;
+    let new = key(line.lines().next().unwrap_or_default());
+    let mut at = first;
+    while at < lines.len() && number(lines[at]).is_some() && key(lines[at]) < new {
+        at += 1;
+    }
+    lines.insert(at, line);
+
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    Some(contents)
+}
+
+fn between<'a>(line: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    line.strip_prefix(prefix)?.strip_suffix(suffix)
+}
+
+fn number_between(line: &str, prefix: &str, suffix: &str) -> Option<u8> {
+    between(line, prefix, suffix)?.parse().ok()
+}
+
+/// The [Day] of a `#[cfg(feature = "...")]` line, which belongs to the day's module or entry below it.
+fn cfg_day(line: &str) -> Option<Day> {
+    Day::of_feature(between(line.trim(), "#[cfg(feature = \"", "\")]")?)
+}
+
+/// The `#[cfg]` attribute that compiles an item only with the feature of `day`.
+fn cfg(day: Day) -> String {
+    format!("#[cfg(feature = \"{}\")]", day.feature())
+}
+
+/// Declare the module of `day` in `src/lib.rs` or in the module of its year.
+fn register_module(lib: &str, day: Day) -> Option<String> {
+    // Only the modules of the same year are in the file.
+    let number = |l: &str| {
+        number_between(l, "pub mod day", ";")
+            .map(|n| Day {
+                year: day.year,
+                day: n,
+            })
+            .or_else(|| cfg_day(l).filter(|d| d.year == day.year))
+    };
+    // rustfmt orders the module declarations by name, so day10 comes before day2. A `#[cfg]` line sorts with the
+    // module below it.
+    let name = |l: &str| {
+        number(l)
+            .map(|d| format!("day{}", d.day))
+            .unwrap_or_default()
+    };
+    let lines = format!("{}\npub mod day{};", cfg(day), day.day);
+    insert_line(lib, &lines, number, name).or_else(|| {
+        // The module of a new year has nothing but its docs yet.
+        (!lib.contains("pub mod")).then(|| format!("{lib}{lines}\n"))
+    })
+}
+
+/// Declare the module of a `year` other than [YEAR] in `src/lib.rs`, in rustfmt order.
+fn register_year(lib: &str, year: u16) -> Option<String> {
+    let name = |l: &str| between(l, "pub mod ", ";").map(str::to_string);
+    let module = format!("y{year}");
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let last = lines.iter().rposition(|l| name(l).is_some())?;
+    let at = lines
+        .iter()
+        .position(|l| name(l).is_some_and(|n| n > module))
+        .unwrap_or(last + 1);
+    let line = format!("pub mod {module};");
+    lines.insert(at, &line);
+
+    let mut lib = lines.join("\n");
+    lib.push('\n');
+    Some(lib)
+}
+
+fn register_entry(registry: &str, day: Day) -> Option<String> {
+    let number = |l: &str| {
+        let Some(rest) = l.trim_start().strip_prefix("Entry::new::<crate::") else {
+            return cfg_day(l);
+        };
+        let (year, rest) = match rest.strip_prefix('y') {
+            Some(rest) => {
+                let (year, rest) = rest.split_once("::")?;
+                (year.parse().ok()?, rest)
+            }
+            None => (YEAR, rest),
+        };
+        let day = rest.strip_prefix("day")?.split("::").next()?.parse().ok()?;
+        Some(Day { year, day })
+    };
+    let lines = format!(
+        "    {}\n    Entry::new::<crate::{}::Day{}>(),",
+        cfg(day),
+        day.module(),
+        day.day
+    );
+    insert_line(registry, &lines, number, |l| number(l))
+}
+
+/// Register the binary and the feature of `day` in the manifest.
+fn register_manifest(manifest: &str, day: Day) -> Option<String> {
+    register_feature(&register_bin(manifest, day)?, day)
+}
+
+/// Declare the feature of `day` and add it to the `all` feature.
+fn register_feature(manifest: &str, day: Day) -> Option<String> {
+    let declared = |l: &str| Day::of_feature(l.strip_suffix(" = []")?);
+    let manifest = insert_line(
+        manifest,
+        &format!("{} = []", day.feature()),
+        declared,
+        declared,
+    )?;
+    let all = |l: &str| Day::of_feature(between(l.trim(), "\"", "\",")?);
+    insert_line(&manifest, &format!("    \"{}\",", day.feature()), all, all)
+}
+
+fn register_bin(manifest: &str, day: Day) -> Option<String> {
+    let block = format!(
+        "[[bin]]\nname = \"{feature}\"\npath = \"src/{dir}/main.rs\"\nrequired-features = [\"{feature}\"]\n\n",
+        feature = day.feature(),
+        dir = crate::day_dir(day.year, day.day).display(),
+    );
+    let number = |name: &str| Day::of_feature(between(name, "name = \"", "\"")?);
+
+    let mut at = None;
+    let mut offset = 0;
+    for section in manifest.split_inclusive("\n\n") {
+        if section.starts_with("[[bin]]") {
+            match section.lines().find_map(number) {
+                Some(d) if d > day => {
+                    at = Some(offset);
+                    break;
+                }
+                Some(_) => at = Some(offset + section.len()),
+                None => {}
+            }
+        }
+        offset += section.len();
+    }
+
+    let at = at?;
+    let mut manifest = manifest.to_string();
+    manifest.insert_str(at, &block);
+    Some(manifest)
+}
+
+/// The [Day] and part of a status row of the README, such as `- [x] 2022 Day 4 Part 1 (12.5µs)`.
+///
+/// Rows may carry notes after the part, such as `(example verified, 39.7µs)`, so only their prefix is read.
+fn status_row(line: &str) -> Option<(Day, u8)> {
+    let rest = line.strip_prefix("- [")?.get(3..)?;
+    let (year, rest) = match rest.split_once(" Day ") {
+        Some((year, rest)) => (year.parse().ok()?, rest),
+        None => (YEAR, rest.strip_prefix("Day ")?),
+    };
+    let mut words = rest.split(' ');
+    let day = words.next()?.parse().ok()?;
+    let part: u8 = match (words.next()?, words.next()?) {
+        ("Part", part) => part.parse().ok()?,
+        _ => return None,
+    };
+    Some((Day { year, day }, part))
+}
+
+fn register_status(readme: &str, day: Day) -> Option<String> {
+    let row = |part| format!("- [ ] {} Part {part}", day.status());
+    let readme = insert_line(readme, &row(1), status_row, status_row)?;
+    insert_line(&readme, &row(2), status_row, status_row)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, contents).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn create_dir(path: &Path) -> Result<(), ScaffoldError> {
+    fs::create_dir_all(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_registered, register_bin, register_entry, register_feature, register_module,
+        register_status, register_year, render, Day, Outcome, Scaffold, ScaffoldError,
+    };
+    use crate::YEAR;
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+    };
+
+    fn day(day: u8) -> Day {
+        Day { year: YEAR, day }
+    }
+
+    /// A crate in the temporary directory named after `name` with the templates and `files`.
+    fn temp_crate(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let templates = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates");
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("templates")).unwrap();
+        for template in ["mod.rs.tmpl", "main.rs.tmpl"] {
+            fs::copy(
+                templates.join(template),
+                root.join("templates").join(template),
+            )
+            .unwrap();
+        }
+        for (file, contents) in files {
+            fs::write(root.join(file), contents).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn render_substitutes_day_and_year() {
+        assert_eq!(
+            render("Day{day} of {year}: {x}", 7, 2021),
+            "Day7 of 2021: {x}"
+        );
+        assert_eq!(
+            render("aoc::{module}::Day{day}", 7, 2022),
+            "aoc::y2022::day7::Day7"
+        );
+    }
+
+    #[test]
+    fn module_is_inserted_in_rustfmt_order() {
+        let lib = "//! docs\n#[cfg(feature = \"day1\")]\npub mod day1;\n#[cfg(feature = \"day3\")]\npub mod day3;\npub mod input;\n";
+        assert_eq!(
+            register_module(lib, day(2)).unwrap(),
+            "//! docs\n#[cfg(feature = \"day1\")]\npub mod day1;\n#[cfg(feature = \"day2\")]\npub mod day2;\n#[cfg(feature = \"day3\")]\npub mod day3;\npub mod input;\n"
+        );
+        assert_eq!(
+            register_module(lib, day(10)).unwrap(),
+            "//! docs\n#[cfg(feature = \"day1\")]\npub mod day1;\n#[cfg(feature = \"day10\")]\npub mod day10;\n#[cfg(feature = \"day3\")]\npub mod day3;\npub mod input;\n"
+        );
+    }
+
+    #[test]
+    fn entry_is_inserted_in_day_order() {
+        let registry = "pub const DAYS: &[Entry] = &[\n    #[cfg(feature = \"day2\")]\n    Entry::new::<crate::day2::Day2>(),\n    #[cfg(feature = \"day10\")]\n    Entry::new::<crate::day10::Day10>(),\n];\n";
+        assert_eq!(
+            register_entry(registry, day(4)).unwrap(),
+            "pub const DAYS: &[Entry] = &[\n    #[cfg(feature = \"day2\")]\n    Entry::new::<crate::day2::Day2>(),\n    #[cfg(feature = \"day4\")]\n    Entry::new::<crate::day4::Day4>(),\n    #[cfg(feature = \"day10\")]\n    Entry::new::<crate::day10::Day10>(),\n];\n"
+        );
+    }
+
+    #[test]
+    fn bin_is_inserted_in_day_order() {
+        let manifest = "[package]\nname = \"x\"\n\n[[bin]]\nname = \"aoc\"\npath = \"src/main.rs\"\n\n[[bin]]\nname = \"day1\"\npath = \"src/day1/main.rs\"\n\n[[bin]]\nname = \"day3\"\npath = \"src/day3/main.rs\"\n\n[dependencies]\n";
+        let manifest = register_bin(manifest, day(2)).unwrap();
+        assert!(manifest.contains("\"src/day1/main.rs\"\n\n[[bin]]\nname = \"day2\"\npath = \"src/day2/main.rs\"\nrequired-features = [\"day2\"]\n\n[[bin]]\nname = \"day3\""));
+        let manifest = register_bin(&manifest, day(4)).unwrap();
+        assert!(manifest.contains("\"src/day3/main.rs\"\n\n[[bin]]\nname = \"day4\"\npath = \"src/day4/main.rs\"\nrequired-features = [\"day4\"]\n\n[dependencies]"));
+    }
+
+    #[test]
+    fn feature_is_declared_in_day_order() {
+        let manifest = "[features]\ndefault = [\"all\"]\nall = [\n    \"day1\",\n    \"day3\",\n]\nday1 = []\nday3 = []\n";
+        assert_eq!(
+            register_feature(manifest, day(2)).unwrap(),
+            "[features]\ndefault = [\"all\"]\nall = [\n    \"day1\",\n    \"day2\",\n    \"day3\",\n]\nday1 = []\nday2 = []\nday3 = []\n"
+        );
+    }
+
+    #[test]
+    fn status_rows_are_inserted_in_order() {
+        let readme = "# Status\n- [x] Day 1 Part 1\n- [ ] Day 1 Part 2\n- [ ] Day 3 Part 1\n- [ ] Day 3 Part 2\n";
+        assert_eq!(
+            register_status(readme, day(2)).unwrap(),
+            "# Status\n- [x] Day 1 Part 1\n- [ ] Day 1 Part 2\n- [ ] Day 2 Part 1\n- [ ] Day 2 Part 2\n- [ ] Day 3 Part 1\n- [ ] Day 3 Part 2\n"
+        );
+
+        let readme = "- [x] Day 1 Part 1 (answer verified, 39.7µs)\n- [x] Day 1 Part 2 (answer verified, 61.7µs)\n- [x] Day 3 Part 1 (12.5µs)\n";
+        assert_eq!(
+            register_status(readme, day(2)).unwrap(),
+            "- [x] Day 1 Part 1 (answer verified, 39.7µs)\n- [x] Day 1 Part 2 (answer verified, 61.7µs)\n- [ ] Day 2 Part 1\n- [ ] Day 2 Part 2\n- [x] Day 3 Part 1 (12.5µs)\n"
+        );
+    }
+
+    #[test]
+    fn generate_into_crate() {
+        let root = temp_crate(
+            "scaffold",
+            &[
+                (
+                    "src/lib.rs",
+                    "#[cfg(feature = \"day1\")]\npub mod day1;\nmod solution;\n",
+                ),
+                (
+                    "src/registry.rs",
+                    "pub const DAYS: &[Entry] = &[\n    #[cfg(feature = \"day1\")]\n    Entry::new::<crate::day1::Day1>(),\n];\n",
+                ),
+                (
+                    "Cargo.toml",
+                    "[features]\nall = [\n    \"day1\",\n]\nday1 = []\n\n[[bin]]\nname = \"day1\"\npath = \"src/day1/main.rs\"\nrequired-features = [\"day1\"]\n\n[dependencies]\n",
+                ),
+                ("README.md", "# Status\n- [ ] Day 1 Part 1\n- [ ] Day 1 Part 2\n"),
+            ],
+        );
+
+        let scaffold = Scaffold::new(&root, 2021, false);
+        assert_eq!(scaffold.generate(2).unwrap(), Outcome::Created);
+        assert!(matches!(
+            scaffold.generate(2),
+            Err(ScaffoldError::Exists(2))
+        ));
+        assert_eq!(scaffold.generate_missing(2).unwrap(), Outcome::Skipped);
+
+        let module = fs::read_to_string(root.join("src/day2/mod.rs")).unwrap();
+        assert!(module.contains("pub struct Day2;"));
+        assert!(module.contains("https://adventofcode.com/2021/day/2"));
+        assert_eq!(
+            fs::read_to_string(root.join("src/day2/input.txt")).unwrap(),
+            ""
+        );
+
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert_eq!(
+            lib,
+            "#[cfg(feature = \"day1\")]\npub mod day1;\n#[cfg(feature = \"day2\")]\npub mod day2;\nmod solution;\n"
+        );
+
+        fs::write(root.join("src/day2/input.txt"), "1\n").unwrap();
+        let scaffold = Scaffold::new(&root, 2021, true);
+        assert_eq!(scaffold.generate(2).unwrap(), Outcome::Overwritten);
+        assert_eq!(
+            fs::read_to_string(root.join("src/day2/input.txt")).unwrap(),
+            "1\n"
+        );
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert_eq!(manifest.matches("name = \"day2\"").count(), 1);
+        assert!(manifest.starts_with(
+            "[features]\nall = [\n    \"day1\",\n    \"day2\",\n]\nday1 = []\nday2 = []\n"
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn other_years_are_ordered_after_their_own() {
+        let y2022 = |day| Day { year: 2022, day };
+        let registry = "pub const DAYS: &[Entry] = &[\n    #[cfg(feature = \"day10\")]\n    Entry::new::<crate::day10::Day10>(),\n    #[cfg(feature = \"y2022-day3\")]\n    Entry::new::<crate::y2022::day3::Day3>(),\n];\n";
+        assert_eq!(
+            register_entry(registry, y2022(1)).unwrap(),
+            "pub const DAYS: &[Entry] = &[\n    #[cfg(feature = \"day10\")]\n    Entry::new::<crate::day10::Day10>(),\n    #[cfg(feature = \"y2022-day1\")]\n    Entry::new::<crate::y2022::day1::Day1>(),\n    #[cfg(feature = \"y2022-day3\")]\n    Entry::new::<crate::y2022::day3::Day3>(),\n];\n"
+        );
+
+        let readme = "- [ ] Day 25 Part 1\n- [ ] Day 25 Part 2\n- [ ] 2022 Day 3 Part 1\n- [ ] 2022 Day 3 Part 2\n";
+        assert_eq!(
+            register_status(readme, y2022(1)).unwrap(),
+            "- [ ] Day 25 Part 1\n- [ ] Day 25 Part 2\n- [ ] 2022 Day 1 Part 1\n- [ ] 2022 Day 1 Part 2\n- [ ] 2022 Day 3 Part 1\n- [ ] 2022 Day 3 Part 2\n"
+        );
+
+        // The modules of a year only order among themselves.
+        let modules = "//! # Advent of Code 2022\n";
+        let modules = register_module(modules, y2022(3)).unwrap();
+        assert_eq!(
+            register_module(&modules, y2022(10)).unwrap(),
+            "//! # Advent of Code 2022\n#[cfg(feature = \"y2022-day10\")]\npub mod day10;\n#[cfg(feature = \"y2022-day3\")]\npub mod day3;\n"
+        );
+        assert_eq!(
+            register_year("pub mod answers;\npub mod toml;\n", 2022).unwrap(),
+            "pub mod answers;\npub mod toml;\npub mod y2022;\n"
+        );
+    }
+
+    #[test]
+    fn status_rows_of_two_years() {
+        let readme = "- [x] Day 1 Part 1\n- [x] Day 1 Part 2\n- [x] 2022 Day 4 Part 1 (1.5µs)\n- [ ] 2022 Day 4 Part 2\n";
+        assert!(!is_registered("README.md", readme, day(4)));
+        assert!(is_registered(
+            "README.md",
+            readme,
+            Day { year: 2022, day: 4 }
+        ));
+        assert_eq!(
+            register_status(readme, day(4)).unwrap(),
+            "- [x] Day 1 Part 1\n- [x] Day 1 Part 2\n- [ ] Day 4 Part 1\n- [ ] Day 4 Part 2\n- [x] 2022 Day 4 Part 1 (1.5µs)\n- [ ] 2022 Day 4 Part 2\n"
+        );
+    }
+
+    #[test]
+    fn generate_another_year() {
+        let root = temp_crate(
+            "scaffold-year",
+            &[
+                (
+                    "src/lib.rs",
+                    "#[cfg(feature = \"day1\")]\npub mod day1;\npub mod toml;\n",
+                ),
+                (
+                    "src/registry.rs",
+                    "pub const DAYS: &[Entry] = &[\n    #[cfg(feature = \"day1\")]\n    Entry::new::<crate::day1::Day1>(),\n];\n",
+                ),
+                (
+                    "Cargo.toml",
+                    "[features]\nall = [\n    \"day1\",\n]\nday1 = []\n\n[[bin]]\nname = \"day1\"\npath = \"src/day1/main.rs\"\nrequired-features = [\"day1\"]\n\n[dependencies]\n",
+                ),
+                ("README.md", "# Status\n- [ ] Day 1 Part 1\n- [ ] Day 1 Part 2\n"),
+            ],
+        );
+
+        assert!(matches!(
+            Scaffold::new(&root, 1999, false).generate(4),
+            Err(ScaffoldError::InvalidYear(1999))
+        ));
+        let scaffold = Scaffold::new(&root, 2022, false);
+        assert_eq!(scaffold.generate(4).unwrap(), Outcome::Created);
+        assert_eq!(scaffold.generate_missing(4).unwrap(), Outcome::Skipped);
+        assert_eq!(scaffold.generate(2).unwrap(), Outcome::Created);
+
+        let read = |file: &str| fs::read_to_string(root.join(file)).unwrap();
+        assert!(read("src/y2022/day4/mod.rs").contains("const YEAR: u16 = 2022;"));
+        assert!(read("src/y2022/day4/main.rs").contains("aoc::y2022::day4::Day4"));
+        assert_eq!(read("answers/2022.toml"), "");
+        assert_eq!(
+            read("src/lib.rs"),
+            "#[cfg(feature = \"day1\")]\npub mod day1;\npub mod toml;\npub mod y2022;\n"
+        );
+        assert_eq!(
+            read("src/y2022/mod.rs"),
+            "//! # Advent of Code 2022\n//! Solutions for [Advent of Code 2022](https://adventofcode.com/2022).\n#[cfg(feature = \"y2022-day2\")]\npub mod day2;\n#[cfg(feature = \"y2022-day4\")]\npub mod day4;\n"
+        );
+        assert!(read("src/registry.rs").ends_with(
+            "    #[cfg(feature = \"y2022-day2\")]\n    Entry::new::<crate::y2022::day2::Day2>(),\n    #[cfg(feature = \"y2022-day4\")]\n    Entry::new::<crate::y2022::day4::Day4>(),\n];\n"
+        ));
+        let manifest = read("Cargo.toml");
+        assert!(manifest.starts_with(
+            "[features]\nall = [\n    \"day1\",\n    \"y2022-day2\",\n    \"y2022-day4\",\n]\nday1 = []\ny2022-day2 = []\ny2022-day4 = []\n"
+        ));
+        assert!(manifest.contains(
+            "[[bin]]\nname = \"y2022-day4\"\npath = \"src/y2022/day4/main.rs\"\nrequired-features = [\"y2022-day4\"]\n\n[dependencies]"
+        ));
+        assert!(read("README.md").ends_with("- [ ] 2022 Day 4 Part 1\n- [ ] 2022 Day 4 Part 2\n"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
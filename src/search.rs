@@ -0,0 +1,395 @@
+//! Breadth-first, Dijkstra and A* [Search] over any [Graph], for the puzzles that ask for the shortest path through a
+//! cave or the cheapest way to rearrange a burrow.
+//!
+//! Every search reports its [Outcome] along with [Stats] about the states it visited, can be cut short after a number
+//! of expansions, and reconstructs the path it found.
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    marker::PhantomData,
+    ops::Add,
+};
+
+/// A [Graph] given by the successors of each node and the costs of the edges to them.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+    /// The cost of an edge, where [`Default`] is no cost at all.
+    type Cost: Copy + Ord + Add<Output = Self::Cost> + Default;
+
+    /// The nodes reachable from `node` in a single step, with the cost of each step.
+    fn successors(&self, node: &Self::Node) -> Vec<(Self::Node, Self::Cost)>;
+}
+
+/// A [Graph] defined by a successor function, see [`from_fn`].
+pub struct FnGraph<N, C, F> {
+    successors: F,
+    types: PhantomData<fn(&N) -> C>,
+}
+
+/// A [Graph] whose successors are given by the function `successors`.
+pub fn from_fn<N, C, F>(successors: F) -> FnGraph<N, C, F>
+where
+    F: Fn(&N) -> Vec<(N, C)>,
+{
+    FnGraph {
+        successors,
+        types: PhantomData,
+    }
+}
+
+impl<N, C, F> Graph for FnGraph<N, C, F>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: Fn(&N) -> Vec<(N, C)>,
+{
+    type Node = N;
+    type Cost = C;
+
+    fn successors(&self, node: &N) -> Vec<(N, C)> {
+        (self.successors)(node)
+    }
+}
+
+/// How a [Search] ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<N, C> {
+    /// A cheapest path to a goal, from the start to the goal inclusive, and its total cost.
+    Found { path: Vec<N>, cost: C },
+    /// Every reachable node was expanded without finding a goal.
+    Unreachable,
+    /// The [expansion limit](Search::max_expansions) was reached before a goal was found.
+    Limited,
+}
+
+impl<N, C: Copy> Outcome<N, C> {
+    /// The cost of the path that was found, if any.
+    #[must_use]
+    pub fn cost(&self) -> Option<C> {
+        match self {
+            Outcome::Found { cost, .. } => Some(*cost),
+            Outcome::Unreachable | Outcome::Limited => None,
+        }
+    }
+}
+
+/// Statistics about the states a [Search] visited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The nodes whose successors were generated.
+    pub expanded: usize,
+    /// The distinct nodes that were reached.
+    pub discovered: usize,
+    /// The largest number of nodes waiting to be expanded at once.
+    pub max_frontier: usize,
+}
+
+/// The [Outcome] and [Stats] of a [Search].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<N, C> {
+    pub outcome: Outcome<N, C>,
+    pub stats: Stats,
+}
+
+/// The settings of a search, with a method per algorithm.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Search {
+    /// Give up after expanding this many nodes.
+    pub max_expansions: Option<usize>,
+}
+
+impl Search {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn max_expansions(mut self, max_expansions: usize) -> Self {
+        self.max_expansions = Some(max_expansions);
+        self
+    }
+
+    /// Find a path from `start` to a node for which `goal` holds with the fewest steps, ignoring the edge costs.
+    pub fn bfs<G: Graph>(
+        &self,
+        graph: &G,
+        start: G::Node,
+        goal: impl Fn(&G::Node) -> bool,
+    ) -> Report<G::Node, usize> {
+        let mut nodes = Nodes::new(start);
+        let mut steps = vec![0];
+        let mut frontier = VecDeque::from([0]);
+        let mut stats = Stats::default();
+
+        while let Some(i) = frontier.pop_front() {
+            if goal(&nodes.all[i]) {
+                return nodes.found(i, steps[i], stats);
+            }
+            if self.limited(&stats) {
+                return nodes.limited(stats);
+            }
+            stats.expanded += 1;
+            for (next, _) in graph.successors(&nodes.all[i]) {
+                if let (j, true) = nodes.insert(next, i) {
+                    steps.push(steps[i] + 1);
+                    frontier.push_back(j);
+                }
+            }
+            stats.max_frontier = stats.max_frontier.max(frontier.len());
+        }
+        nodes.unreachable(stats)
+    }
+
+    /// Find a cheapest path from `start` to a node for which `goal` holds.
+    pub fn dijkstra<G: Graph>(
+        &self,
+        graph: &G,
+        start: G::Node,
+        goal: impl Fn(&G::Node) -> bool,
+    ) -> Report<G::Node, G::Cost> {
+        self.astar(graph, start, goal, |_| G::Cost::default())
+    }
+
+    /// Find a cheapest path from `start` to a node for which `goal` holds, expanding the nodes with the lowest cost so
+    /// far plus the `heuristic` estimate of the cost left first.
+    ///
+    /// The path is only guaranteed to be the cheapest when the heuristic never overestimates.
+    pub fn astar<G: Graph>(
+        &self,
+        graph: &G,
+        start: G::Node,
+        goal: impl Fn(&G::Node) -> bool,
+        heuristic: impl Fn(&G::Node) -> G::Cost,
+    ) -> Report<G::Node, G::Cost> {
+        let zero = G::Cost::default();
+        let mut nodes = Nodes::new(start);
+        let mut costs = vec![zero];
+        let mut frontier = BinaryHeap::from([Reverse((heuristic(&nodes.all[0]), zero, 0))]);
+        let mut stats = Stats::default();
+
+        while let Some(Reverse((_, cost, i))) = frontier.pop() {
+            // A cheaper way to this node was found after this entry was queued.
+            if cost > costs[i] {
+                continue;
+            }
+            if goal(&nodes.all[i]) {
+                return nodes.found(i, cost, stats);
+            }
+            if self.limited(&stats) {
+                return nodes.limited(stats);
+            }
+            stats.expanded += 1;
+            for (next, step) in graph.successors(&nodes.all[i]) {
+                let next_cost = cost + step;
+                let estimate = next_cost + heuristic(&next);
+                let (j, new) = nodes.insert(next, i);
+                if new {
+                    costs.push(next_cost);
+                } else if next_cost < costs[j] {
+                    costs[j] = next_cost;
+                    nodes.parents[j] = i;
+                } else {
+                    continue;
+                }
+                frontier.push(Reverse((estimate, next_cost, j)));
+            }
+            stats.max_frontier = stats.max_frontier.max(frontier.len());
+        }
+        nodes.unreachable(stats)
+    }
+
+    fn limited(&self, stats: &Stats) -> bool {
+        self.max_expansions.is_some_and(|max| stats.expanded >= max)
+    }
+}
+
+/// A [breadth-first search](Search::bfs) without an expansion limit.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    goal: impl Fn(&G::Node) -> bool,
+) -> Report<G::Node, usize> {
+    Search::new().bfs(graph, start, goal)
+}
+
+/// A [Dijkstra search](Search::dijkstra) without an expansion limit.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    goal: impl Fn(&G::Node) -> bool,
+) -> Report<G::Node, G::Cost> {
+    Search::new().dijkstra(graph, start, goal)
+}
+
+/// An [A* search](Search::astar) without an expansion limit.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    goal: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> G::Cost,
+) -> Report<G::Node, G::Cost> {
+    Search::new().astar(graph, start, goal, heuristic)
+}
+
+/// The nodes reached so far, numbered in the order they were discovered, with the node each was reached from.
+struct Nodes<N> {
+    all: Vec<N>,
+    parents: Vec<usize>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Nodes<N> {
+    fn new(start: N) -> Self {
+        Self {
+            all: vec![start.clone()],
+            parents: vec![0],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// The number of `node` and whether it is new, in which case it was reached from `parent`.
+    fn insert(&mut self, node: N, parent: usize) -> (usize, bool) {
+        match self.index.entry(node) {
+            Entry::Occupied(entry) => (*entry.get(), false),
+            Entry::Vacant(entry) => {
+                let i = self.all.len();
+                self.all.push(entry.key().clone());
+                self.parents.push(parent);
+                entry.insert(i);
+                (i, true)
+            }
+        }
+    }
+
+    /// The path from the start to node `i`, following the parents back.
+    fn path(&self, mut i: usize) -> Vec<N> {
+        let mut path = vec![self.all[i].clone()];
+        while i != 0 {
+            i = self.parents[i];
+            path.push(self.all[i].clone());
+        }
+        path.reverse();
+        path
+    }
+
+    fn found<C>(&self, i: usize, cost: C, stats: Stats) -> Report<N, C> {
+        Report {
+            outcome: Outcome::Found {
+                path: self.path(i),
+                cost,
+            },
+            stats: self.stats(stats),
+        }
+    }
+
+    fn limited<C>(&self, stats: Stats) -> Report<N, C> {
+        Report {
+            outcome: Outcome::Limited,
+            stats: self.stats(stats),
+        }
+    }
+
+    fn unreachable<C>(&self, stats: Stats) -> Report<N, C> {
+        Report {
+            outcome: Outcome::Unreachable,
+            stats: self.stats(stats),
+        }
+    }
+
+    fn stats(&self, stats: Stats) -> Stats {
+        Stats {
+            discovered: self.all.len(),
+            ..stats
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra, from_fn, Outcome, Search};
+    use crate::grid::{Grid, Position};
+
+    /// The risk map from the puzzle of day 15, whose cheapest path from the top left to the bottom right costs 40.
+    const RISKS: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    fn risks() -> Grid<u8> {
+        Grid::parse_digits(RISKS).unwrap()
+    }
+
+    #[test]
+    fn cheapest_path() {
+        let grid = risks();
+        let graph = from_fn(|&p: &Position| {
+            grid.neighbors4(p)
+                .map(|n| (n, u32::from(grid[n])))
+                .collect()
+        });
+        let end = (grid.rows() - 1, grid.cols() - 1);
+
+        let plain = dijkstra(&graph, (0, 0), |&p| p == end);
+        assert_eq!(plain.outcome.cost(), Some(40));
+
+        let manhattan = |&(row, col): &Position| u32::try_from(end.0 - row + end.1 - col).unwrap();
+        let guided = astar(&graph, (0, 0), |&p| p == end, manhattan);
+        assert_eq!(guided.outcome, plain.outcome);
+        assert!(guided.stats.expanded <= plain.stats.expanded);
+
+        let Outcome::Found { path, .. } = plain.outcome else {
+            unreachable!()
+        };
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&end));
+        let cost: u32 = path[1..].iter().map(|&p| u32::from(grid[p])).sum();
+        assert_eq!(cost, 40);
+    }
+
+    #[test]
+    fn fewest_steps() {
+        // Walls of 9s leave a single winding way through.
+        let grid = Grid::parse_digits("0009\n9909\n0009\n0999\n0000").unwrap();
+        let graph = from_fn(|&p: &Position| {
+            grid.neighbors4(p)
+                .filter(|&n| grid[n] != 9)
+                .map(|n| (n, 1u8))
+                .collect()
+        });
+
+        let report = bfs(&graph, (0, 0), |&p| p == (4, 3));
+        assert_eq!(report.outcome.cost(), Some(11));
+        assert_eq!(report.stats.discovered, 12);
+
+        let walled = bfs(&graph, (0, 0), |&p| p == (0, 3));
+        assert_eq!(walled.outcome, Outcome::Unreachable);
+        assert_eq!(walled.stats.expanded, 12);
+    }
+
+    #[test]
+    fn expansion_limit() {
+        let counting = from_fn(|&n: &u64| vec![(n + 1, 1u64), (n * 2, 1)]);
+        let report = Search::new()
+            .max_expansions(5)
+            .dijkstra(&counting, 1, |&n| n == 1000);
+        assert_eq!(report.outcome, Outcome::Limited);
+        assert_eq!(report.stats.expanded, 5);
+
+        let report = bfs(&counting, 1, |&n| n == 12);
+        assert_eq!(
+            report.outcome,
+            Outcome::Found {
+                path: vec![1, 2, 3, 6, 12],
+                cost: 4
+            }
+        );
+    }
+}
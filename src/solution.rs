@@ -0,0 +1,134 @@
+use crate::{
+    input::{InputError, Source},
+    Part,
+};
+use std::{env, io, process::ExitCode};
+use thiserror::Error;
+
+/// The [Result] of parsing or solving a puzzle.
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Input(#[from] InputError),
+    #[error("I/O failed")]
+    Io(#[from] io::Error),
+    #[error("parsing input failed: {0}")]
+    Parse(Box<dyn std::error::Error + Send + Sync>),
+    #[error("solving failed: {0}")]
+    Solve(Box<dyn std::error::Error + Send + Sync>),
+    #[error("not implemented")]
+    Unimplemented,
+    #[error("no solution for day {day} of {year}")]
+    UnknownDay { year: u16, day: u8 },
+    #[error("panicked: {0}")]
+    Panicked(String),
+    #[error(transparent)]
+    Trace(#[from] crate::trace::TraceError),
+    #[error(transparent)]
+    Config(#[from] crate::config::ConfigError),
+}
+
+/// A [Solution] to both parts of a single day's puzzle.
+///
+/// The raw puzzle input is parsed once into [`Solution::Input`] and then handed to each part.
+pub trait Solution: 'static {
+    /// The day of the puzzle, from 1 to 25.
+    const DAY: u8;
+
+    /// The year of the puzzle, which defaults to the [YEAR](crate::YEAR) this crate started with.
+    const YEAR: u16 = crate::YEAR;
+
+    /// The source of the day's module, usually `include_str!("mod.rs")`.
+    ///
+    /// Its `//!` docs hold the [`Example`](crate::examples::Example)s from the puzzle description.
+    const SOURCE: &'static str = "";
+
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+
+    /// The answer produced by both parts, such as an integer, which is turned into an [Answer](crate::Answer) to be
+    /// printed, compared and stored.
+    type Answer: Into<crate::Answer>;
+
+    /// Parse the raw puzzle `input`.
+    ///
+    /// # Errors
+    /// Returns a ``Parse`` error when the `input` is malformed.
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Solve part 1 of the puzzle.
+    ///
+    /// # Errors
+    /// Returns ``Unimplemented`` until the part has been solved.
+    fn part1(_input: &Self::Input) -> Result<Self::Answer> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Solve part 2 of the puzzle.
+    ///
+    /// # Errors
+    /// Returns ``Unimplemented`` until the part has been solved.
+    fn part2(_input: &Self::Input) -> Result<Self::Answer> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Other [Implementation]s of the parts, such as an obvious one next to a fast one, which must always agree with
+    /// [`part1`](Self::part1) and [`part2`](Self::part2).
+    const ALTERNATIVES: &'static [Implementation<Self>] = &[];
+}
+
+/// A named [Implementation] of one [Part] of a [Solution].
+pub struct Implementation<S: Solution + ?Sized> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&S::Input) -> Result<S::Answer>,
+}
+
+/// Read the raw puzzle input for `day` of `year` from the [Source] resolved for `arg`.
+///
+/// # Errors
+/// Returns an ``Input`` error when the input is missing, empty or cannot be read, and a ``Config`` error when the
+/// [`Config`](crate::config::Config) holding the input directory is invalid.
+pub fn read_input(year: u16, day: u8, arg: Option<&str>) -> Result<String> {
+    Ok(Source::resolve(year, day, arg)?.read(day)?)
+}
+
+/// Read the puzzle input for the [Solution] `S`, solve both parts and print the answers.
+///
+/// The input is read from the path given as the first command line argument (`-` for stdin), falling back to the default [Source].
+/// The tracing flags of [`trace::configure`](crate::trace::configure), such as `-v`, may be given as well.
+/// Parts that are not implemented yet are skipped, and any error is reported on stderr with a failing exit code.
+#[must_use]
+pub fn run<S: Solution>() -> ExitCode {
+    let result = try_run::<S>();
+    crate::trace::flush();
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn try_run<S: Solution>() -> Result<()> {
+    let args = crate::trace::configure(env::args().skip(1), &[])?;
+    crate::config::init()?;
+    let input = S::parse(&read_input(
+        S::YEAR,
+        S::DAY,
+        args.first().map(String::as_str),
+    )?)?;
+
+    for part in Part::ALL {
+        match crate::registry::solve_part::<S>(&input, part) {
+            Ok(answer) => println!("part{part}: {answer}"),
+            Err(Error::Unimplemented) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,286 @@
+use crate::{answers::Answers, registry::Entry, Error, Part, YEAR};
+use std::{fmt, time::Duration};
+
+/// The heading of the README section that lists the [`PartStatus`] of every part.
+pub const HEADING: &str = "# Status";
+
+/// Whether a single part of a day is solved, as shown in the README.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartStatus {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The part produced an answer, either for the real input or for an example.
+    pub implemented: bool,
+    /// Whether the examples stating an answer for this part all match, if there are any.
+    pub examples: Option<bool>,
+    /// Whether the answer for the real input matches the accepted answer in `answers.toml`, if there is one.
+    pub verified: Option<bool>,
+    /// How long the part took on the real input.
+    pub elapsed: Option<Duration>,
+}
+
+impl PartStatus {
+    /// A part is done when it is implemented and contradicts neither an example nor its accepted answer.
+    #[must_use]
+    pub fn done(&self) -> bool {
+        self.implemented && self.examples != Some(false) && self.verified != Some(false)
+    }
+
+    /// Render the status row without the run time, which differs from run to run.
+    #[must_use]
+    pub fn row(&self) -> String {
+        let mut row = self.to_string();
+        if let Some(stripped) = strip_time(&row) {
+            row = stripped;
+        }
+        row
+    }
+}
+
+impl fmt::Display for PartStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let check = if self.done() { 'x' } else { ' ' };
+        write!(f, "- [{check}] ")?;
+        // Rows of other years are told apart by their year, while those of the crate's own year keep their names.
+        if self.year != YEAR {
+            write!(f, "{} ", self.year)?;
+        }
+        write!(f, "Day {} Part {}", self.day, self.part)?;
+
+        let mut notes = Vec::new();
+        match self.examples {
+            Some(true) => notes.push("example verified".to_string()),
+            Some(false) if self.implemented => notes.push("example failed".to_string()),
+            _ => {}
+        }
+        match self.verified {
+            Some(true) => notes.push("answer verified".to_string()),
+            Some(false) => notes.push("answer mismatch".to_string()),
+            None => {}
+        }
+        if let Some(elapsed) = self.elapsed {
+            notes.push(format!("{elapsed:.1?}"));
+        }
+        if !notes.is_empty() {
+            write!(f, " ({})", notes.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Remove a trailing run time such as `12.3µs` from the notes of a status `row`.
+fn strip_time(row: &str) -> Option<String> {
+    let (head, notes) = row.strip_suffix(')')?.rsplit_once(" (")?;
+    let notes: Vec<&str> = notes
+        .split(", ")
+        .filter(|note| !(note.starts_with(|c: char| c.is_ascii_digit()) && note.ends_with('s')))
+        .collect();
+
+    Some(if notes.is_empty() {
+        head.to_string()
+    } else {
+        format!("{head} ({})", notes.join(", "))
+    })
+}
+
+/// Solve every part of every registered day of `year` to find its [`PartStatus`], with the accepted `answers` of the
+/// year.
+///
+/// Days without an input yet are judged by their examples alone.
+#[must_use]
+pub fn collect(year: u16, answers: &Answers) -> Vec<PartStatus> {
+    crate::registry::days(year)
+        .flat_map(|entry| day_status(entry, answers))
+        .collect()
+}
+
+fn day_status(entry: &Entry, answers: &Answers) -> Vec<PartStatus> {
+    let run = crate::read_input(entry.year, entry.day, None)
+        .and_then(|input| entry.solve(&input, &Part::ALL));
+
+    Part::ALL
+        .iter()
+        .map(|&part| {
+            let examples = entry.check_examples(part);
+            let answer = run
+                .as_ref()
+                .ok()
+                .and_then(|run| run.parts.iter().find(|p| p.part == part));
+
+            let verified = answer
+                .and_then(|p| p.answer.as_ref().ok())
+                .zip(answers.get(entry.day, part))
+                .map(|(actual, expected)| *actual == expected);
+
+            let (implemented, elapsed) = match answer {
+                Some(p) if p.answer.is_ok() => (true, Some(p.elapsed)),
+                Some(p) if !matches!(p.answer, Err(Error::Unimplemented)) => (true, None),
+                _ => (examples == Some(true), None),
+            };
+
+            PartStatus {
+                year: entry.year,
+                day: entry.day,
+                part,
+                implemented,
+                examples,
+                verified,
+                elapsed,
+            }
+        })
+        .collect()
+}
+
+/// Replace the status section of `readme` with a list of `statuses`.
+///
+/// The section runs from the [HEADING] to the next top-level heading and is appended when missing.
+#[must_use]
+pub fn rewrite(readme: &str, statuses: &[PartStatus]) -> String {
+    let mut section = format!("{HEADING}\n");
+    for status in statuses {
+        section.push_str(&status.to_string());
+        section.push('\n');
+    }
+
+    let Some(start) = find_section(readme) else {
+        let mut readme = readme.trim_end().to_string();
+        readme.push_str("\n\n");
+        readme.push_str(&section);
+        return readme;
+    };
+    let end = next_heading(readme, start);
+
+    let mut rewritten = readme[..start].to_string();
+    rewritten.push_str(&section);
+    if end < readme.len() {
+        rewritten.push('\n');
+        rewritten.push_str(&readme[end..]);
+    }
+    rewritten
+}
+
+/// Whether the status section of `readme` matches `statuses`, ignoring run times.
+#[must_use]
+pub fn is_current(readme: &str, statuses: &[PartStatus]) -> bool {
+    let Some(start) = find_section(readme) else {
+        return false;
+    };
+    let end = next_heading(readme, start);
+
+    let rows: Vec<String> = readme[start..end]
+        .lines()
+        .skip(1)
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| strip_time(l).unwrap_or_else(|| l.to_string()))
+        .collect();
+    let expected: Vec<String> = statuses.iter().map(PartStatus::row).collect();
+
+    rows == expected
+}
+
+fn find_section(readme: &str) -> Option<usize> {
+    if readme.starts_with(&format!("{HEADING}\n")) {
+        return Some(0);
+    }
+    readme.find(&format!("\n{HEADING}\n")).map(|i| i + 1)
+}
+
+fn next_heading(readme: &str, start: usize) -> usize {
+    let body = start + HEADING.len() + 1;
+    readme[body..]
+        .find("\n# ")
+        .map_or(readme.len(), |i| body + i + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_current, rewrite, PartStatus};
+    use crate::Part;
+    use std::time::Duration;
+
+    fn statuses() -> Vec<PartStatus> {
+        vec![
+            PartStatus {
+                year: 2021,
+                day: 1,
+                part: Part::One,
+                implemented: true,
+                examples: Some(true),
+                verified: Some(true),
+                elapsed: Some(Duration::from_micros(35)),
+            },
+            PartStatus {
+                year: 2021,
+                day: 1,
+                part: Part::Two,
+                implemented: true,
+                examples: Some(false),
+                verified: None,
+                elapsed: None,
+            },
+            PartStatus {
+                year: 2021,
+                day: 2,
+                part: Part::One,
+                implemented: false,
+                examples: None,
+                verified: None,
+                elapsed: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn rows() {
+        let rows: Vec<String> = statuses().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            rows,
+            [
+                "- [x] Day 1 Part 1 (example verified, answer verified, 35.0µs)",
+                "- [ ] Day 1 Part 2 (example failed)",
+                "- [ ] Day 2 Part 1",
+            ]
+        );
+        assert_eq!(
+            statuses()[0].row(),
+            "- [x] Day 1 Part 1 (example verified, answer verified)"
+        );
+    }
+
+    #[test]
+    fn other_years_are_named() {
+        let status = PartStatus {
+            year: 2022,
+            ..statuses()[2].clone()
+        };
+        assert_eq!(status.to_string(), "- [ ] 2022 Day 2 Part 1");
+    }
+
+    #[test]
+    fn rewrite_replaces_only_the_status_section() {
+        let readme = "# Title\ntext\n\n# Status\n- [ ] Day 1 Part 1\n\n# License\nMIT\n";
+        let rewritten = rewrite(readme, &statuses());
+        assert_eq!(
+            rewritten,
+            "# Title\ntext\n\n# Status\n- [x] Day 1 Part 1 (example verified, answer verified, 35.0µs)\n- [ ] Day 1 Part 2 (example failed)\n- [ ] Day 2 Part 1\n\n# License\nMIT\n"
+        );
+        assert!(is_current(&rewritten, &statuses()));
+        assert!(!is_current(readme, &statuses()));
+    }
+
+    #[test]
+    fn rewrite_appends_a_missing_section() {
+        let rewritten = rewrite("# Title\n", &statuses()[2..]);
+        assert_eq!(rewritten, "# Title\n\n# Status\n- [ ] Day 2 Part 1\n");
+    }
+
+    #[test]
+    fn run_times_are_ignored() {
+        let mut statuses = statuses();
+        let readme = rewrite("", &statuses);
+        statuses[0].elapsed = Some(Duration::from_millis(3));
+        assert!(is_current(&readme, &statuses));
+    }
+}
@@ -0,0 +1,548 @@
+//! Submitting answers to the website, and the [Guesses] that keep obviously wrong answers from being submitted again.
+//!
+//! The website only says whether an answer is right, too high or too low, and makes you wait a while after a wrong
+//! one. [Guesses] remembers all of that per part, so an answer that is already known to be wrong, or that is outside
+//! of the bounds given by earlier hints, is refused locally instead of costing another wait.
+use crate::{
+    answers::Answers,
+    client::{Client, ClientError},
+    http::Request,
+    toml::{Document, TomlError, Value},
+    Answer, Part,
+};
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SubmitError {
+    #[error("not submitting {answer}: {refusal}")]
+    Refused { answer: String, refusal: Refusal },
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error("the response could not be understood: {0}")]
+    UnknownResponse(String),
+}
+
+#[derive(Error, Debug)]
+pub enum GuessesError {
+    #[error("reading {} failed", .path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("writing {} failed", .path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("{} is invalid", .path.display())]
+    Toml {
+        path: PathBuf,
+        #[source]
+        source: TomlError,
+    },
+}
+
+/// The verdict of the website on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Wrong,
+    /// An answer was submitted too recently; the website accepts another one after this long.
+    Wait(Duration),
+    /// The part has already been solved, or part 1 has not been solved yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Parse the page the website responds with.
+    #[must_use]
+    pub fn parse(page: &str) -> Option<Self> {
+        let text = article_text(page);
+        if text.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if text.contains("your answer is too high") {
+            Some(Outcome::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Some(Outcome::TooLow)
+        } else if text.contains("That's not the right answer") {
+            Some(Outcome::Wrong)
+        } else if text.contains("You gave an answer too recently") {
+            Some(Outcome::Wait(left_to_wait(&text).unwrap_or(Duration::ZERO)))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(Outcome::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait(wait) => write!(f, "submitted too recently; wait {}s", wait.as_secs()),
+            Outcome::WrongLevel => write!(f, "already solved or not unlocked yet"),
+        }
+    }
+}
+
+/// The text of the `<article>` of a `page`, without tags.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// How long the website says is left to wait, as in "You have 1m 23s left to wait."
+fn left_to_wait(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once("left to wait")?;
+    let (_, time) = before
+        .rsplit_once("You have")
+        .or(before.rsplit_once("you have"))?;
+    let mut seconds = 0;
+    for part in time.split_whitespace() {
+        let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        seconds += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// How long the website asks to wait after a wrong answer, as in "please wait 5 minutes before trying again".
+fn cooldown(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once("before trying again")?;
+    let (_, wait) = before.rsplit_once("wait ")?;
+    let (n, unit) = wait.trim().split_once(' ')?;
+    let n = match n {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    match unit.trim_end_matches('s') {
+        "second" => Some(Duration::from_secs(n)),
+        "minute" => Some(Duration::from_secs(n * 60)),
+        _ => None,
+    }
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part has an accepted answer already.
+    Solved(Answer),
+    /// The same answer was wrong before.
+    Guessed,
+    /// An answer this high or higher was too high.
+    TooHigh(i64),
+    /// An answer this low or lower was too low.
+    TooLow(i64),
+    /// The website will not accept another answer yet.
+    Wait(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Solved(answer) => write!(f, "the part is already solved with {answer}"),
+            Refusal::Guessed => write!(f, "it was submitted before and was wrong"),
+            Refusal::TooHigh(bound) => write!(f, "{bound} was already too high"),
+            Refusal::TooLow(bound) => write!(f, "{bound} was already too low"),
+            Refusal::Wait(wait) => write!(f, "the website needs another {}s", wait.as_secs()),
+        }
+    }
+}
+
+/// The wrong [Guesses] submitted so far and what they tell about the right answer, kept in a TOML file per year with a
+/// `[day<N>]` table per day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Guesses {
+    document: Document,
+}
+
+impl Guesses {
+    /// The default location of the store of `year`, inside `target/`.
+    #[must_use]
+    pub fn default_path(year: u16) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("target")
+            .join(crate::year_file("aoc-guesses.toml", year))
+    }
+
+    /// Load the store from `path`, which is empty when the file does not exist.
+    ///
+    /// # Errors
+    /// Returns a [`GuessesError`] when the file cannot be read or is not valid TOML.
+    pub fn load(path: &Path) -> Result<Self, GuessesError> {
+        let toml = match fs::read_to_string(path) {
+            Ok(toml) => toml,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(GuessesError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+
+        let document = Document::parse(&toml).map_err(|source| GuessesError::Toml {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(Self { document })
+    }
+
+    /// Write the store to `path`, creating its directory.
+    ///
+    /// # Errors
+    /// Returns a [`GuessesError`] when the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), GuessesError> {
+        let write = |source| GuessesError::Write {
+            path: path.to_path_buf(),
+            source,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(write)?;
+        }
+        fs::write(path, self.document.to_string()).map_err(write)
+    }
+
+    /// The wrong answers to `part` of `day`, in the order they were submitted.
+    #[must_use]
+    pub fn wrong(&self, day: u8, part: Part) -> Vec<String> {
+        match self.get(day, &format!("part{part}_wrong")) {
+            Some(Value::Array(values)) => values
+                .iter()
+                .filter_map(|v| match v {
+                    Value::String(s) => Some(s.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The highest answer to `part` of `day` known to be too low.
+    #[must_use]
+    pub fn low(&self, day: u8, part: Part) -> Option<i64> {
+        self.integer(day, &format!("part{part}_low"))
+    }
+
+    /// The lowest answer to `part` of `day` known to be too high.
+    #[must_use]
+    pub fn high(&self, day: u8, part: Part) -> Option<i64> {
+        self.integer(day, &format!("part{part}_high"))
+    }
+
+    /// Why submitting `answer` to `part` of `day` at time `now` is pointless, if it is.
+    #[must_use]
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: SystemTime) -> Option<Refusal> {
+        if let Some(wait) = self
+            .wait_until()
+            .and_then(|until| until.duration_since(now).ok())
+        {
+            return Some(Refusal::Wait(wait));
+        }
+        if self.wrong(day, part).iter().any(|wrong| wrong == answer) {
+            return Some(Refusal::Guessed);
+        }
+        let n = answer.trim().parse::<i64>().ok()?;
+        match (self.low(day, part), self.high(day, part)) {
+            (Some(low), _) if n <= low => Some(Refusal::TooLow(low)),
+            (_, Some(high)) if n >= high => Some(Refusal::TooHigh(high)),
+            _ => None,
+        }
+    }
+
+    /// Remember the `outcome` of submitting `answer` to `part` of `day` at time `now`, along with the `cooldown` the
+    /// website asked for.
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: Part,
+        answer: &str,
+        outcome: Outcome,
+        cooldown: Option<Duration>,
+        now: SystemTime,
+    ) {
+        if let Some(wait) = cooldown.or(match outcome {
+            Outcome::Wait(wait) => Some(wait),
+            _ => None,
+        }) {
+            let until = (now + wait).duration_since(UNIX_EPOCH).unwrap_or_default();
+            self.document.table_mut("").set(
+                "wait_until",
+                Value::Integer(i64::try_from(until.as_secs()).unwrap_or(i64::MAX)),
+            );
+        }
+
+        if !matches!(outcome, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong) {
+            return;
+        }
+        let table = self.document.table_mut(&format!("day{day}"));
+        let mut wrong = match table.get(&format!("part{part}_wrong")) {
+            Some(Value::Array(values)) => values.clone(),
+            _ => Vec::new(),
+        };
+        wrong.push(Value::String(answer.to_string()));
+        table.set(&format!("part{part}_wrong"), Value::Array(wrong));
+
+        let Ok(n) = answer.trim().parse::<i64>() else {
+            return;
+        };
+        match outcome {
+            Outcome::TooLow if self.low(day, part).is_none_or(|low| n > low) => {
+                self.document
+                    .table_mut(&format!("day{day}"))
+                    .set(&format!("part{part}_low"), Value::Integer(n));
+            }
+            Outcome::TooHigh if self.high(day, part).is_none_or(|high| n < high) => {
+                self.document
+                    .table_mut(&format!("day{day}"))
+                    .set(&format!("part{part}_high"), Value::Integer(n));
+            }
+            _ => {}
+        }
+    }
+
+    /// When the website accepts answers again.
+    fn wait_until(&self) -> Option<SystemTime> {
+        match self.document.table("")?.get("wait_until")? {
+            Value::Integer(secs) => {
+                Some(UNIX_EPOCH + Duration::from_secs(u64::try_from(*secs).ok()?))
+            }
+            _ => None,
+        }
+    }
+
+    fn get(&self, day: u8, key: &str) -> Option<&Value> {
+        self.document.table(&format!("day{day}"))?.get(key)
+    }
+
+    fn integer(&self, day: u8, key: &str) -> Option<i64> {
+        match self.get(day, key)? {
+            Value::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+/// Submit `answer` to `part` of `day` unless the [Answers] or [Guesses] show that it is pointless, and record the
+/// [Outcome] in both.
+///
+/// The caller saves the stores afterwards.
+///
+/// # Errors
+/// Returns a [`SubmitError`] when the answer is refused locally, the request fails, or the response is not understood.
+pub fn submit(
+    client: &Client,
+    answers: &mut Answers,
+    guesses: &mut Guesses,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome, SubmitError> {
+    let now = SystemTime::now();
+    let refusal = match answers.get(day, part) {
+        Some(accepted) => Some(Refusal::Solved(accepted)),
+        None => guesses.check(day, part, answer, now),
+    };
+    if let Some(refusal) = refusal {
+        return Err(SubmitError::Refused {
+            answer: answer.to_string(),
+            refusal,
+        });
+    }
+
+    let url = format!("{}/{}/day/{day}/answer", client.base_url, client.year);
+    let level = part.to_string();
+    let response = client.send(&Request::post(
+        &url,
+        &[("level", &level), ("answer", answer)],
+    ))?;
+    if response.status != 200 {
+        return Err(crate::client::unexpected(&response).into());
+    }
+
+    let outcome = Outcome::parse(&response.body).ok_or_else(|| {
+        SubmitError::UnknownResponse(article_text(&response.body).trim().to_string())
+    })?;
+    if outcome == Outcome::Correct {
+        answers.set(day, part, Answer::parse(answer));
+    }
+    guesses.record(
+        day,
+        part,
+        answer,
+        outcome,
+        cooldown(&article_text(&response.body)),
+        now,
+    );
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cooldown, submit, Guesses, Outcome, Refusal, SubmitError};
+    use crate::{answers::Answers, client::tests::client, http::stub::Stub, Answer, Part};
+    use std::{
+        env, fs,
+        time::{Duration, SystemTime},
+    };
+
+    fn page(text: &str) -> String {
+        format!("<html><main><article><p>{text}</p></article></main></html>")
+    }
+
+    #[test]
+    fn parse_outcomes() {
+        let outcome = |text: &str| Outcome::parse(&page(text));
+
+        assert_eq!(
+            outcome("That's the right answer! You are <em>one gold star</em> closer."),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too high. Please wait one minute before trying again."),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too low."),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            outcome("That's not the right answer. If you're stuck, ..."),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            outcome("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 23s left to wait."),
+            Some(Outcome::Wait(Duration::from_secs(83)))
+        );
+        assert_eq!(
+            outcome("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(outcome("Something else"), None);
+
+        assert_eq!(
+            cooldown("Please wait one minute before trying again."),
+            Some(Duration::from_mins(1))
+        );
+        assert_eq!(
+            cooldown("please wait 5 minutes before trying again."),
+            Some(Duration::from_mins(5))
+        );
+    }
+
+    #[test]
+    fn guesses_refuse_known_wrong_answers() {
+        let now = SystemTime::now();
+        let mut guesses = Guesses::default();
+        guesses.record(1, Part::One, "100", Outcome::TooHigh, None, now);
+        guesses.record(1, Part::One, "10", Outcome::TooLow, None, now);
+        guesses.record(1, Part::One, "20", Outcome::TooLow, None, now);
+        guesses.record(1, Part::One, "50", Outcome::Wrong, None, now);
+
+        let check = |answer| guesses.check(1, Part::One, answer, now);
+        assert_eq!(check("50"), Some(Refusal::Guessed));
+        assert_eq!(check("120"), Some(Refusal::TooHigh(100)));
+        assert_eq!(check("15"), Some(Refusal::TooLow(20)));
+        assert_eq!(check("42"), None);
+        assert_eq!(guesses.check(1, Part::Two, "50", now), None);
+
+        guesses.record(
+            1,
+            Part::Two,
+            "7",
+            Outcome::Wrong,
+            Some(Duration::from_mins(1)),
+            now,
+        );
+        assert!(matches!(
+            guesses.check(1, Part::One, "42", now),
+            Some(Refusal::Wait(_))
+        ));
+        let later = now + Duration::from_secs(61);
+        assert_eq!(guesses.check(1, Part::One, "42", later), None);
+
+        let path = env::temp_dir().join(format!("aoc-guesses-{}.toml", std::process::id()));
+        guesses.save(&path).unwrap();
+        let loaded = Guesses::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, guesses);
+        assert_eq!(loaded.wrong(1, Part::One), ["100", "10", "20", "50"]);
+    }
+
+    #[test]
+    fn submit_against_a_fake_server() {
+        let stub = Stub::serve(|request| {
+            let text = if request.ends_with("answer=5") {
+                "That's the right answer!"
+            } else {
+                "That's not the right answer; your answer is too low. Please wait one minute before trying again."
+            };
+            (200, page(text))
+        });
+        let (client, dir) = client(&stub.url, "submit");
+        let mut answers = Answers::default();
+        let mut guesses = Guesses::default();
+
+        let outcome = submit(&client, &mut answers, &mut guesses, 2, Part::Two, "3").unwrap();
+        assert_eq!(outcome, Outcome::TooLow);
+        assert_eq!(guesses.low(2, Part::Two), Some(3));
+        assert!(matches!(
+            submit(&client, &mut answers, &mut guesses, 2, Part::Two, "5"),
+            Err(SubmitError::Refused {
+                refusal: Refusal::Wait(_),
+                ..
+            })
+        ));
+
+        let mut guesses = Guesses::default();
+        let outcome = submit(&client, &mut answers, &mut guesses, 2, Part::Two, "5").unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+        assert_eq!(answers.get(2, Part::Two), Some(Answer::from(5u8)));
+        assert!(matches!(
+            submit(&client, &mut answers, &mut guesses, 2, Part::Two, "5"),
+            Err(SubmitError::Refused {
+                refusal: Refusal::Solved(_),
+                ..
+            })
+        ));
+
+        let _ = fs::remove_dir_all(&dir);
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2021/day/2/answer "));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=3"));
+    }
+}
@@ -0,0 +1,349 @@
+//! A reader and writer for the subset of TOML used by this crate's files: `[table]` headers and `key = value` lines with
+//! string, integer and boolean values, and single-line arrays of them.
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("invalid TOML on line {line}: {message}")]
+pub struct TomlError {
+    pub line: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Bool(bool),
+    Array(Vec<Value>),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::String(s) => {
+                write!(f, "\"")?;
+                for c in s.chars() {
+                    match c {
+                        '"' => write!(f, "\\\"")?,
+                        '\\' => write!(f, "\\\\")?,
+                        '\n' => write!(f, "\\n")?,
+                        '\t' => write!(f, "\\t")?,
+                        c => write!(f, "{c}")?,
+                    }
+                }
+                write!(f, "\"")
+            }
+            Value::Integer(n) => write!(f, "{n}"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// A [Table] of `key = value` entries in file order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Table {
+    pub name: String,
+    pub entries: Vec<(String, Value)>,
+}
+
+impl Table {
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Set `key` to `value`, replacing an existing entry in place.
+    pub fn set(&mut self, key: &str, value: Value) {
+        match self.entries.iter_mut().find(|(k, _)| k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.entries.push((key.to_string(), value)),
+        }
+    }
+}
+
+/// A TOML [Document]. Entries before the first header belong to a root [Table] with an empty name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    pub tables: Vec<Table>,
+}
+
+impl Document {
+    /// Parse a TOML `input`.
+    ///
+    /// # Errors
+    /// Returns a [`TomlError`] naming the first line that is not part of the supported subset.
+    pub fn parse(input: &str) -> Result<Self, TomlError> {
+        let mut document = Document::default();
+
+        for (i, line) in input.lines().enumerate() {
+            let error = |message: &str| TomlError {
+                line: i + 1,
+                message: message.to_string(),
+            };
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[') {
+                let name = name
+                    .strip_suffix(']')
+                    .ok_or_else(|| error("expected ']'"))?
+                    .trim();
+                if name.is_empty() {
+                    return Err(error("empty table name"));
+                }
+                if document.table(name).is_some() {
+                    return Err(error(&format!("table [{name}] defined twice")));
+                }
+                document.tables.push(Table {
+                    name: name.to_string(),
+                    entries: Vec::new(),
+                });
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `key = value`"))?;
+            let key = key.trim();
+            if key.is_empty()
+                || !key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                return Err(error(&format!("invalid key `{key}`")));
+            }
+            let value = parse_value(value.trim()).map_err(|message| error(&message))?;
+
+            if document.tables.is_empty() {
+                document.tables.push(Table::default());
+            }
+            let last = document.tables.len() - 1;
+            let table = &mut document.tables[last];
+            if table.get(key).is_some() {
+                return Err(error(&format!("key `{key}` defined twice")));
+            }
+            table.entries.push((key.to_string(), value));
+        }
+
+        Ok(document)
+    }
+
+    #[must_use]
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        self.tables.iter().find(|t| t.name == name)
+    }
+
+    /// The [Table] called `name`, appending an empty one when it does not exist yet.
+    ///
+    /// The root [Table] is inserted first, as its entries have to come before any header.
+    pub fn table_mut(&mut self, name: &str) -> &mut Table {
+        if let Some(i) = self.tables.iter().position(|t| t.name == name) {
+            return &mut self.tables[i];
+        }
+        let table = Table {
+            name: name.to_string(),
+            entries: Vec::new(),
+        };
+        let i = if name.is_empty() {
+            0
+        } else {
+            self.tables.len()
+        };
+        self.tables.insert(i, table);
+        &mut self.tables[i]
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for table in &self.tables {
+            if table.name.is_empty() && table.entries.is_empty() {
+                continue;
+            }
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+            if !table.name.is_empty() {
+                writeln!(f, "[{}]", table.name)?;
+            }
+            for (key, value) in &table.entries {
+                writeln!(f, "{key} = {value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Remove a `#` comment that is not inside a string.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut chars = line.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_value(value: &str) -> Result<Value, String> {
+    if let Some(items) = value.strip_prefix('[') {
+        let items = items
+            .strip_suffix(']')
+            .ok_or_else(|| "unterminated array".to_string())?;
+        return split_items(items)
+            .into_iter()
+            .map(|item| match parse_value(item)? {
+                Value::Array(_) => Err("nested arrays are not supported".to_string()),
+                value => Ok(value),
+            })
+            .collect::<Result<_, _>>()
+            .map(Value::Array);
+    }
+
+    if let Some(s) = value.strip_prefix('"') {
+        let s = s
+            .strip_suffix('"')
+            .ok_or_else(|| "unterminated string".to_string())?;
+        let mut unescaped = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                unescaped.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('"') => unescaped.push('"'),
+                Some('\\') => unescaped.push('\\'),
+                Some('n') => unescaped.push('\n'),
+                Some('t') => unescaped.push('\t'),
+                _ => return Err("invalid escape in string".to_string()),
+            }
+        }
+        return Ok(Value::String(unescaped));
+    }
+
+    match value {
+        "true" => Ok(Value::Bool(true)),
+        "false" => Ok(Value::Bool(false)),
+        n => n
+            .replace('_', "")
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| format!("unsupported value `{n}`")),
+    }
+}
+
+/// Split the `items` of an array at the commas that are not inside a string, allowing a trailing comma.
+fn split_items(items: &str) -> Vec<&str> {
+    let mut split = Vec::new();
+    let mut in_string = false;
+    let mut start = 0;
+    let mut chars = items.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            ',' if !in_string => {
+                split.push(items[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    split.push(items[start..].trim());
+    if split.last() == Some(&"") {
+        split.pop();
+    }
+    split
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Document, Table, TomlError, Value};
+
+    #[test]
+    fn parse() {
+        let document = Document::parse(
+            "# answers\nyear = 2021\n\n[day1]\npart1 = \"12 # not a comment\" # a comment\nsolved = true\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            document.tables,
+            [
+                Table {
+                    name: String::new(),
+                    entries: vec![("year".to_string(), Value::Integer(2021))],
+                },
+                Table {
+                    name: "day1".to_string(),
+                    entries: vec![
+                        (
+                            "part1".to_string(),
+                            Value::String("12 # not a comment".to_string())
+                        ),
+                        ("solved".to_string(), Value::Bool(true)),
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut document = Document::default();
+        document
+            .table_mut("day2")
+            .set("part1", Value::String("a \"quoted\"\nanswer".to_string()));
+        document.table_mut("day2").set("part2", Value::Integer(-7));
+        document.table_mut("day3").set("part1", Value::Bool(false));
+        document.table_mut("day3").set(
+            "wrong",
+            Value::Array(vec![Value::String("1, 2".to_string()), Value::Integer(3)]),
+        );
+
+        let toml = document.to_string();
+        assert_eq!(
+            toml,
+            "[day2]\npart1 = \"a \\\"quoted\\\"\\nanswer\"\npart2 = -7\n\n[day3]\npart1 = false\nwrong = [\"1, 2\", 3]\n"
+        );
+        assert_eq!(Document::parse(&toml).unwrap(), document);
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        assert_eq!(
+            Document::parse("[day1]\npart1 = \"x\"\npart1 = \"y\"\n"),
+            Err(TomlError {
+                line: 3,
+                message: "key `part1` defined twice".to_string()
+            })
+        );
+        assert_eq!(Document::parse("[day1\n").unwrap_err().line, 1);
+        assert_eq!(Document::parse("x = [1, [2]]\n").unwrap_err().line, 1);
+        assert_eq!(Document::parse("x = [1\n").unwrap_err().line, 1);
+    }
+}
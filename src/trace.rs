@@ -0,0 +1,287 @@
+//! Leveled tracing of the intermediate steps of a solution, for finding out why an answer is wrong without adding
+//! `println!`s.
+//!
+//! Days emit structured events with [`event!`](crate::event), a name followed by `key = value` fields that are
+//! written with their `Debug` implementation:
+//!
+//! ```
+//! # let (step, depth) = (3, 10);
+//! aoc::event!(Trace, "step", step = step, depth = depth);
+//! ```
+//!
+//! Every event is one line, such as `trace aoc::day2: step step=3 depth=10`, written to stderr or to the output given
+//! to [`set_output`]. Tracing is off until [`set_level`] turns it on, and an event that is not enabled costs a single
+//! atomic load: its fields are not even evaluated.
+//!
+//! The binaries turn tracing on with [`configure`], from `-v` for [`Level::Debug`], `-vv` for [`Level::Trace`] and
+//! `--trace-file <PATH>` to write the events to a file.
+use std::{
+    fmt::{self, Write as _},
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Mutex, PoisonError,
+    },
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TraceError {
+    #[error("--trace-file needs a value")]
+    MissingPath,
+    #[error("creating trace file {} failed", .path.display())]
+    Create {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// How much detail to trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// The milestones of a part, such as the winning board, enabled with `-v`.
+    Debug = 1,
+    /// Every step along with the state it leaves behind, enabled with `-vv`.
+    Trace = 2,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Debug => f.pad("debug"),
+            Level::Trace => f.pad("trace"),
+        }
+    }
+}
+
+impl Level {
+    /// The [Level] of a number of `-v` flags, where 0 turns tracing off.
+    #[must_use]
+    pub fn from_verbosity(verbosity: usize) -> Option<Self> {
+        match verbosity {
+            0 => None,
+            1 => Some(Level::Debug),
+            _ => Some(Level::Trace),
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+/// Where events go, or stderr when `None`.
+static OUTPUT: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+/// Trace events up to `level`, or none at all.
+pub fn set_level(level: Option<Level>) {
+    LEVEL.store(level.map_or(0, |l| l as u8), Ordering::Relaxed);
+}
+
+/// Whether events of `level` are traced.
+#[inline]
+#[must_use]
+pub fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Write events to `output` instead of stderr.
+pub fn set_output(output: impl Write + Send + 'static) {
+    let mut current = OUTPUT.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(previous) = current.as_mut() {
+        // Events are best effort and must not fail a run.
+        let _ = previous.flush();
+    }
+    *current = Some(Box::new(output));
+}
+
+/// Write events to the file at `path`, replacing it.
+///
+/// # Errors
+/// Returns an error when the file cannot be created.
+pub fn to_file(path: &Path) -> io::Result<()> {
+    set_output(BufWriter::new(File::create(path)?));
+    Ok(())
+}
+
+/// Take the tracing flags out of the command line `args`, turn tracing on as they ask and return the other arguments.
+///
+/// The flags may appear anywhere before a `--`: `-v` and `--verbose` raise the [Level] by one, `-vv` by two, and
+/// `--trace-file <PATH>` writes the events to `PATH` instead of stderr. The value that follows any of the options
+/// that `take_values`, such as `--answer`, is left alone even when it looks like a flag, and so is everything after a
+/// `--`, which is dropped itself.
+///
+/// # Errors
+/// Returns a [`TraceError`] when `--trace-file` has no path or the file cannot be created.
+pub fn configure(
+    args: impl IntoIterator<Item = String>,
+    take_values: &[&str],
+) -> Result<Vec<String>, TraceError> {
+    let mut verbosity = 0;
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-v" | "--verbose" => verbosity += 1,
+            "-vv" => verbosity += 2,
+            "--trace-file" => {
+                let path = PathBuf::from(args.next().ok_or(TraceError::MissingPath)?);
+                to_file(&path).map_err(|source| TraceError::Create { path, source })?;
+            }
+            "--" => {
+                rest.extend(args);
+                break;
+            }
+            option if take_values.contains(&option) => {
+                rest.push(arg);
+                rest.extend(args.next());
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    set_level(Level::from_verbosity(verbosity));
+    Ok(rest)
+}
+
+/// Flush the events written so far, such as before exiting.
+pub fn flush() {
+    if let Some(output) = OUTPUT
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_mut()
+    {
+        let _ = output.flush();
+    }
+}
+
+/// Write an event from the module `target`. Use [`event!`](crate::event) instead, which skips disabled events.
+#[doc(hidden)]
+pub fn emit(level: Level, target: &str, name: &str, fields: &[(&str, &dyn fmt::Debug)]) {
+    let mut line = format!("{level} {target}: {name}");
+    for (key, value) in fields {
+        let _ = write!(line, " {key}={value:?}");
+    }
+    line.push('\n');
+
+    let mut output = OUTPUT.lock().unwrap_or_else(PoisonError::into_inner);
+    let _ = match output.as_mut() {
+        Some(output) => output.write_all(line.as_bytes()),
+        None => io::stderr().write_all(line.as_bytes()),
+    };
+}
+
+/// Trace an event at a [Level] with a name and `key = value` fields, when that level is enabled.
+///
+/// The fields are only evaluated when the event is traced.
+#[macro_export]
+macro_rules! event {
+    ($level:ident, $name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::emit(
+                $crate::trace::Level::$level,
+                module_path!(),
+                $name,
+                &[$((stringify!($key), &$value as &dyn ::std::fmt::Debug)),*],
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{configure, set_level, set_output, Level, TraceError};
+    use std::{
+        io::{self, Write},
+        sync::{Arc, Mutex},
+    };
+
+    /// Held by the tests that change the level, which is global.
+    static LEVEL: Mutex<()> = Mutex::new(());
+
+    /// An output that can be read back while the tracer holds it.
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn events_are_leveled_and_structured() {
+        let _level = LEVEL.lock().unwrap();
+        let output = Shared::default();
+        set_output(output.clone());
+
+        let mut evaluated = false;
+        let mut evaluate = |n: u32| {
+            evaluated = true;
+            n
+        };
+        crate::event!(Debug, "unit-test-off", n = evaluate(1));
+        assert!(!evaluated);
+
+        set_level(Level::from_verbosity(1));
+        crate::event!(Debug, "unit-test-debug", step = 3, command = "up");
+        crate::event!(Trace, "unit-test-trace", step = 4);
+        set_level(Level::from_verbosity(2));
+        crate::event!(Trace, "unit-test-trace", step = 5);
+        set_level(None);
+
+        // Other tests may trace while the level is up, so only look at the events of this one.
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = output
+            .lines()
+            .filter(|l| l.contains(": unit-test-"))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                r#"debug aoc::trace::tests: unit-test-debug step=3 command="up""#,
+                "trace aoc::trace::tests: unit-test-trace step=5",
+            ]
+        );
+    }
+
+    #[test]
+    fn flags_are_taken_out() {
+        let _level = LEVEL.lock().unwrap();
+        let args = |args: &[&str]| args.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        let rest = configure(args(&["run", "-v", "4", "--part", "1"]), &[]).unwrap();
+        assert_eq!(rest, ["run", "4", "--part", "1"]);
+        let rest = configure(args(&["run", "--input", "-", "--verbose", "-vv"]), &[]).unwrap();
+        assert_eq!(rest, ["run", "--input", "-"]);
+        assert!(matches!(
+            configure(args(&["run", "--trace-file"]), &[]),
+            Err(TraceError::MissingPath)
+        ));
+
+        // Values of options and everything after `--` are not flags.
+        let rest = configure(
+            args(&[
+                "submit",
+                "--answer",
+                "-v",
+                "-i",
+                "-vv",
+                "-v",
+                "--",
+                "--verbose",
+            ]),
+            &["--answer", "-i"],
+        )
+        .unwrap();
+        assert_eq!(rest, ["submit", "--answer", "-v", "-i", "-vv", "--verbose"]);
+        assert!(super::enabled(Level::Debug) && !super::enabled(Level::Trace));
+
+        configure(args(&["run"]), &[]).unwrap();
+        assert!(!super::enabled(Level::Debug));
+    }
+}